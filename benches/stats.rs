@@ -0,0 +1,89 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use magic_craft_bevy::stats::{
+    ComputedStats, DirtyStats, ModifierKind, ModifierSource, Modifiers, Stat, StatCalculators,
+    StatTemplate,
+};
+
+const ENTITIES: usize = 1_000;
+
+fn base_entries() -> Vec<(Stat, ModifierKind, f32)> {
+    vec![
+        (Stat::MaxLife, ModifierKind::Flat, 30.0),
+        (Stat::PhysicalDamage, ModifierKind::Flat, 5.0),
+        (Stat::MovementSpeed, ModifierKind::Flat, 250.0),
+        (Stat::AreaOfEffect, ModifierKind::Increased, 0.2),
+        (Stat::SplashRadius, ModifierKind::Flat, 40.0),
+    ]
+}
+
+fn wave_entries(i: usize) -> [(Stat, ModifierKind, f32); 2] {
+    let scale = i as f32 * 0.001;
+    [
+        (Stat::MaxLife, ModifierKind::More, scale),
+        (Stat::PhysicalDamage, ModifierKind::More, scale),
+    ]
+}
+
+fn recalculate(c: &mut Criterion) {
+    let calculators = StatCalculators::build();
+    let template = StatTemplate::build(&calculators, base_entries());
+    let mut entities: Vec<(Modifiers, DirtyStats, ComputedStats)> = (0..ENTITIES)
+        .map(|i| template.instantiate(&calculators, ModifierSource::WaveScaling, wave_entries(i)))
+        .collect();
+
+    c.bench_function("recalculate_1000_all_dirty", |b| {
+        b.iter(|| {
+            for (modifiers, dirty, computed) in &mut entities {
+                dirty.mark_all(Stat::iter());
+                calculators.recalculate(modifiers, computed, dirty);
+            }
+            black_box(&entities);
+        })
+    });
+
+    c.bench_function("recalculate_1000_one_dirty", |b| {
+        b.iter(|| {
+            for (modifiers, dirty, computed) in &mut entities {
+                calculators.invalidate(Stat::AreaOfEffect, dirty);
+                calculators.recalculate(modifiers, computed, dirty);
+            }
+            black_box(&entities);
+        })
+    });
+
+    c.bench_function("instantiate_1000_from_template", |b| {
+        b.iter(|| {
+            let spawned: Vec<_> = (0..ENTITIES)
+                .map(|i| template.instantiate(&calculators, ModifierSource::WaveScaling, wave_entries(i)))
+                .collect();
+            black_box(spawned);
+        })
+    });
+
+    c.bench_function("build_1000_without_template", |b| {
+        b.iter(|| {
+            let spawned: Vec<_> = (0..ENTITIES)
+                .map(|i| {
+                    StatTemplate::build(&calculators, base_entries()).instantiate(
+                        &calculators,
+                        ModifierSource::WaveScaling,
+                        wave_entries(i),
+                    )
+                })
+                .collect();
+            black_box(spawned);
+        })
+    });
+
+    c.bench_function("clone_1000_template_modifiers", |b| {
+        let (modifiers, _, _) =
+            template.instantiate(&calculators, ModifierSource::WaveScaling, wave_entries(0));
+        b.iter(|| {
+            let cloned: Vec<_> = (0..ENTITIES).map(|_| modifiers.clone()).collect();
+            black_box(cloned);
+        })
+    });
+}
+
+criterion_group!(benches, recalculate);
+criterion_main!(benches);
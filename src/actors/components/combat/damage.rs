@@ -2,13 +2,13 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 
 use crate::actors::Player;
-use crate::artifact::{Burning, Frozen, OnHitEffectStack};
+use crate::artifact::{frozen, Burning, ChainHit, OnHitEffectStack};
 use crate::hit_flash::HitFlash;
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, Stat};
+use crate::stats::{ApplyTimedModifier, ComputedStats, Stat, TimeSinceHit};
 use crate::wave::InvulnerableStack;
 
-use super::Health;
+use super::{Health, LastHit, PendingHeal};
 
 #[derive(Message)]
 pub struct PendingDamage {
@@ -27,6 +27,73 @@ pub struct Shield {
 }
 
 const SHIELD_HIT_COOLDOWN: f32 = 1.5;
+const DEFAULT_CRIT_MULTIPLIER: f32 = 1.5;
+pub const MAX_DAMAGE_REDUCTION: f32 = 0.75;
+pub const ARMOR_MIN_FRACTION: f32 = 0.2;
+
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Defense {
+    pub armor: f32,
+    pub reduction: f32,
+    pub taken_increased: f32,
+}
+
+impl Defense {
+    pub fn of(stats: &ComputedStats) -> Self {
+        Self {
+            armor: stats.final_of(Stat::Armor),
+            reduction: stats.final_of(Stat::DamageReduction),
+            taken_increased: stats.final_of(Stat::DamageTakenIncreased),
+        }
+    }
+
+    pub fn mitigate(self, amount: f32) -> f32 {
+        let amount = amount * (1.0 + self.taken_increased).max(0.0);
+        let amount = (amount - self.armor.max(0.0)).max(amount * ARMOR_MIN_FRACTION);
+        amount * (1.0 - self.reduction.clamp(0.0, MAX_DAMAGE_REDUCTION))
+    }
+}
+
+/// Order: crit, damage taken, armor, damage reduction, then the shield absorbs the rest.
+pub fn resolve_damage(
+    raw: f32,
+    crit_multiplier: f32,
+    defense: Defense,
+    shield: Option<&mut Shield>,
+) -> f32 {
+    let mut amount = defense.mitigate(raw * crit_multiplier);
+    if let Some(shield) = shield {
+        let absorbed = amount.min(shield.current);
+        shield.current -= absorbed;
+        amount -= absorbed;
+        shield.recharge_cooldown = SHIELD_HIT_COOLDOWN;
+    }
+    amount
+}
+
+/// Takes `amount` off the target's life and records the hit. Hits on a target already at zero
+/// leave `LastHit` alone, so the killing blow keeps its source and overkill.
+pub fn land_hit(
+    health: &mut Health,
+    last_hit: Option<&mut LastHit>,
+    amount: f32,
+    max_life: f32,
+    source: Option<Entity>,
+) {
+    let before = health.current;
+    health.current = (before - amount).clamp(0.0, max_life);
+    if let Some(last_hit) = last_hit.filter(|_| before > 0.0) {
+        *last_hit = LastHit { source, overkill: (amount - before).max(0.0) };
+    }
+}
+
+fn roll_crit(source: &ComputedStats) -> f32 {
+    if rand::random::<f32>() >= source.final_of(Stat::CritChance) {
+        return 1.0;
+    }
+    let multiplier = source.final_of(Stat::CritMultiplier);
+    if multiplier > 0.0 { multiplier } else { DEFAULT_CRIT_MULTIPLIER }
+}
 
 pub fn register_systems(app: &mut App) {
     app.add_message::<PendingDamage>()
@@ -45,9 +112,13 @@ pub fn apply_pending_damage(
     mut shield_q: Query<&mut Shield>,
     mut velocity_q: Query<(&Transform, &mut LinearVelocity)>,
     transform_q: Query<&Transform>,
+    mut timed: MessageWriter<ApplyTimedModifier>,
+    mut since_hit_q: Query<&mut TimeSinceHit>,
+    mut chains: MessageWriter<ChainHit>,
+    mut last_hit_q: Query<&mut LastHit>,
+    mut heals: MessageWriter<PendingHeal>,
 ) {
     let mut to_emit: Vec<PendingDamage> = Vec::new();
-    let mut to_heal: Vec<(Entity, f32)> = Vec::new();
     let drained: Vec<PendingDamage> = pending.drain().collect();
 
     for hit in drained {
@@ -79,14 +150,13 @@ pub fn apply_pending_damage(
             continue;
         }
 
-        let mut amount = hit.amount;
-
-        if let Ok(mut shield) = shield_q.get_mut(hit.target) {
-            let absorbed = amount.min(shield.current);
-            shield.current -= absorbed;
-            amount -= absorbed;
-            shield.recharge_cooldown = SHIELD_HIT_COOLDOWN;
-        }
+        let crit = hit
+            .source
+            .and_then(|src| stats_q.get(src).ok())
+            .map_or(1.0, roll_crit);
+        let defense = stats_q.get(hit.target).map(Defense::of).unwrap_or_default();
+        let mut shield = shield_q.get_mut(hit.target).ok();
+        let amount = resolve_damage(hit.amount, crit, defense, shield.as_deref_mut());
 
         if amount <= 0.0 {
             if let Ok(mut ec) = commands.get_entity(hit.target) {
@@ -95,19 +165,12 @@ pub fn apply_pending_damage(
             continue;
         }
 
-        if let Some(source_entity) = hit.source {
-            if let Ok(source_stats) = stats_q.get(source_entity) {
-                let chance = source_stats.final_of(Stat::CritChance);
-                if rand::random::<f32>() < chance {
-                    let multiplier = source_stats.final_of(Stat::CritMultiplier);
-                    let effective = if multiplier > 0.0 { multiplier } else { 1.5 };
-                    amount *= effective;
-                }
-            }
-        }
-
         if let Ok(mut health) = health_q.get_mut(hit.target) {
-            health.current = (health.current - amount).clamp(0.0, target_max_life);
+            let mut last_hit = last_hit_q.get_mut(hit.target).ok();
+            land_hit(&mut health, last_hit.as_deref_mut(), amount, target_max_life, hit.source);
+        }
+        if let Ok(mut since_hit) = since_hit_q.get_mut(hit.target) {
+            since_hit.0 = 0.0;
         }
 
         if let Ok(mut ec) = commands.get_entity(hit.target) {
@@ -117,7 +180,10 @@ pub fn apply_pending_damage(
         if hit.on_hit.lifesteal_pct > 0.0 {
             if let Some(src) = hit.source {
                 if player_q.contains(src) {
-                    to_heal.push((src, amount * hit.on_hit.lifesteal_pct));
+                    heals.write(PendingHeal {
+                        target: src,
+                        amount: amount * hit.on_hit.lifesteal_pct,
+                    });
                 }
             }
         }
@@ -147,15 +213,23 @@ pub fn apply_pending_damage(
         }
         if let Some((chance, duration)) = hit.on_hit.freeze {
             if rand::random::<f32>() < chance {
-                if let Ok(mut ec) = commands.get_entity(hit.target) {
-                    ec.insert(Frozen {
-                        remaining: duration,
-                        slow_pct: 0.6,
-                    });
-                }
+                timed.write(ApplyTimedModifier {
+                    target: hit.target,
+                    modifier: frozen(duration),
+                });
             }
         }
 
+        if let Some(jumps) = hit.on_hit.chain {
+            chains.write(ChainHit {
+                origin: hit.target,
+                jumps,
+                amount: hit.amount,
+                source: hit.source,
+                on_hit: hit.on_hit,
+            });
+        }
+
         if target_is_player && target_thorns > 0.0 {
             if let Some(src) = hit.source {
                 to_emit.push(PendingDamage {
@@ -168,16 +242,6 @@ pub fn apply_pending_damage(
         }
     }
 
-    for (entity, heal) in to_heal {
-        if let Ok(mut health) = health_q.get_mut(entity) {
-            let max = stats_q
-                .get(entity)
-                .map(|s| s.final_of(Stat::MaxLife).max(1.0))
-                .unwrap_or(1.0);
-            health.current = (health.current + heal).min(max);
-        }
-    }
-
     for ev in to_emit {
         pending.write(ev);
     }
@@ -195,3 +259,88 @@ fn tick_shield(time: Res<Time>, mut q: Query<&mut Shield>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shield(current: f32) -> Shield {
+        Shield {
+            max_block: current,
+            current,
+            recharge: 0.0,
+            recharge_cooldown: 0.0,
+        }
+    }
+
+    #[test]
+    fn crit_applies_before_mitigation() {
+        let defense = Defense { armor: 5.0, ..default() };
+        assert_eq!(resolve_damage(10.0, 2.0, defense, None), 15.0);
+    }
+
+    #[test]
+    fn damage_taken_then_armor_then_reduction() {
+        let defense = Defense {
+            armor: 4.0,
+            reduction: 0.5,
+            taken_increased: 0.2,
+        };
+        // 10 * 1.2 = 12, - 4 armor = 8, * 0.5 = 4
+        assert!((resolve_damage(10.0, 1.0, defense, None) - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn shield_absorbs_mitigated_damage() {
+        let defense = Defense { reduction: 0.5, ..default() };
+        let mut s = shield(3.0);
+        assert_eq!(resolve_damage(10.0, 1.0, defense, Some(&mut s)), 2.0);
+        assert_eq!(s.current, 0.0);
+        assert_eq!(s.recharge_cooldown, SHIELD_HIT_COOLDOWN);
+
+        let mut s = shield(10.0);
+        assert_eq!(resolve_damage(10.0, 2.0, defense, Some(&mut s)), 0.0);
+        assert_eq!(s.current, 0.0);
+    }
+
+    #[test]
+    fn armor_and_reduction_are_bounded() {
+        let defense = Defense {
+            armor: 100.0,
+            reduction: 5.0,
+            taken_increased: -3.0,
+        };
+        assert_eq!(defense.mitigate(10.0), 0.0);
+
+        let defense = Defense { armor: 100.0, reduction: 5.0, ..default() };
+        let expected = 10.0 * ARMOR_MIN_FRACTION * (1.0 - MAX_DAMAGE_REDUCTION);
+        assert!((defense.mitigate(10.0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fatal_hit_records_killer_and_overkill() {
+        let (player, enemy) = (Entity::from_bits(1), Entity::from_bits(2));
+        let mut health = Health { current: 10.0 };
+        let mut last_hit = LastHit::default();
+
+        land_hit(&mut health, Some(&mut last_hit), 4.0, 10.0, Some(enemy));
+        assert_eq!(health.current, 6.0);
+        assert_eq!((last_hit.source, last_hit.overkill), (Some(enemy), 0.0));
+
+        land_hit(&mut health, Some(&mut last_hit), 9.0, 10.0, Some(player));
+        assert_eq!(health.current, 0.0);
+        assert_eq!((last_hit.source, last_hit.overkill), (Some(player), 3.0));
+    }
+
+    #[test]
+    fn hits_on_a_dead_target_keep_the_killer() {
+        let (player, enemy) = (Entity::from_bits(1), Entity::from_bits(2));
+        let mut health = Health { current: 2.0 };
+        let mut last_hit = LastHit::default();
+        land_hit(&mut health, Some(&mut last_hit), 5.0, 10.0, Some(player));
+
+        land_hit(&mut health, Some(&mut last_hit), 50.0, 10.0, Some(enemy));
+        assert_eq!(health.current, 0.0);
+        assert_eq!((last_hit.source, last_hit.overkill), (Some(player), 3.0));
+    }
+}
@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use crate::schedule::PostGameSet;
 use crate::GameState;
 
-use super::Health;
+use super::{Health, LastHit};
 
 #[derive(Component)]
 pub struct Dead;
@@ -14,6 +14,8 @@ pub struct SkipCleanup;
 #[derive(Message)]
 pub struct DeathEvent {
     pub entity: Entity,
+    pub killer: Option<Entity>,
+    pub overkill: f32,
 }
 
 pub fn register_systems(app: &mut App) {
@@ -28,11 +30,16 @@ pub fn register_systems(app: &mut App) {
 pub fn death_system(
     mut commands: Commands,
     mut death_events: MessageWriter<DeathEvent>,
-    query: Query<(Entity, &Health), (Changed<Health>, Without<Dead>)>,
+    query: Query<(Entity, &Health, Option<&LastHit>), (Changed<Health>, Without<Dead>)>,
 ) {
-    for (entity, health) in &query {
+    for (entity, health, last_hit) in &query {
         if health.current <= 0.0 {
-            death_events.write(DeathEvent { entity });
+            let last_hit = last_hit.copied().unwrap_or_default();
+            death_events.write(DeathEvent {
+                entity,
+                killer: last_hit.source,
+                overkill: last_hit.overkill,
+            });
             if let Ok(mut entity_commands) = commands.get_entity(entity) {
                 entity_commands.insert(Dead);
             }
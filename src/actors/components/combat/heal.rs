@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+
+use crate::actors::Player;
+use crate::run::StartWaveEvent;
+use crate::schedule::GameSet;
+use crate::stats::{ComputedStats, Stat};
+
+use super::damage::apply_pending_damage;
+use super::Health;
+
+#[derive(Message)]
+pub struct PendingHeal {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+pub fn register_systems(app: &mut App) {
+    app.add_message::<PendingHeal>().add_systems(
+        Update,
+        (
+            (regenerate_life, heal_on_wave_start),
+            apply_pending_heal.after(apply_pending_damage),
+        )
+            .chain()
+            .in_set(GameSet::DamageApply),
+    );
+}
+
+fn apply_pending_heal(
+    mut heals: MessageReader<PendingHeal>,
+    mut health_q: Query<(&mut Health, Option<&ComputedStats>)>,
+) {
+    for heal in heals.read() {
+        let Ok((mut health, stats)) = health_q.get_mut(heal.target) else { continue };
+        health.current = healed_life(health.current, heal.amount, stats);
+    }
+}
+
+/// Dead targets stay dead; healing is scaled by HealingReceived and never lifts life past MaxLife.
+fn healed_life(current: f32, amount: f32, stats: Option<&ComputedStats>) -> f32 {
+    if current <= 0.0 || amount <= 0.0 {
+        return current;
+    }
+    let (amount, max) = stats.map_or((amount, f32::MAX), |s| {
+        (
+            amount * s.scale_of(Stat::HealingReceived),
+            s.final_of(Stat::MaxLife).max(1.0),
+        )
+    });
+    if current < max {
+        (current + amount).min(max)
+    } else {
+        current
+    }
+}
+
+fn regenerate_life(
+    time: Res<Time>,
+    q: Query<(Entity, &ComputedStats), With<Player>>,
+    mut heals: MessageWriter<PendingHeal>,
+) {
+    let dt = time.delta_secs();
+    for (entity, stats) in &q {
+        let regen = stats.final_of(Stat::LifeRegen);
+        if regen > 0.0 {
+            heals.write(PendingHeal { target: entity, amount: regen * dt });
+        }
+    }
+}
+
+fn heal_on_wave_start(
+    mut events: MessageReader<StartWaveEvent>,
+    player_q: Query<(Entity, &ComputedStats), With<Player>>,
+    mut heals: MessageWriter<PendingHeal>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    let Ok((player, stats)) = player_q.single() else { return };
+    let pct = stats.final_of(Stat::WaveStartHeal);
+    if pct > 0.0 {
+        heals.write(PendingHeal {
+            target: player,
+            amount: stats.final_of(Stat::MaxLife) * pct,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(max_life: f32, healing_received: f32) -> ComputedStats {
+        let mut s = ComputedStats::default();
+        s.set_final(Stat::MaxLife, max_life);
+        s.set_final(Stat::HealingReceived, healing_received);
+        s
+    }
+
+    #[test]
+    fn heal_is_scaled_by_healing_received_and_clamped_to_max_life() {
+        let s = stats(100.0, 0.5);
+        assert_eq!(healed_life(50.0, 10.0, Some(&s)), 65.0);
+        assert_eq!(healed_life(90.0, 10.0, Some(&s)), 100.0);
+        assert_eq!(healed_life(0.0, 10.0, Some(&s)), 0.0);
+        assert_eq!(healed_life(50.0, 10.0, Some(&stats(100.0, -1.5))), 50.0);
+    }
+}
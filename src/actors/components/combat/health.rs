@@ -1,6 +1,14 @@
 use bevy::prelude::*;
 
 #[derive(Component)]
+#[require(LastHit)]
 pub struct Health {
     pub current: f32,
 }
+
+/// Who last damaged a living target, and how far past zero the killing blow went.
+#[derive(Component, Default, Clone, Copy)]
+pub struct LastHit {
+    pub source: Option<Entity>,
+    pub overkill: f32,
+}
@@ -5,6 +5,7 @@ mod caster;
 mod damage;
 mod damage_payload;
 mod death;
+mod heal;
 mod health;
 mod melee_attacker;
 mod on_collision_damage;
@@ -14,7 +15,8 @@ mod shot_fired;
 pub use caster::Caster;
 pub use damage::PendingDamage;
 pub use death::{death_system, Dead, DeathEvent, SkipCleanup};
-pub use health::Health;
+pub use heal::PendingHeal;
+pub use health::{Health, LastHit};
 pub use melee_attacker::MeleeAttacker;
 pub use on_collision_damage::OnCollisionDamage;
 pub use projectile::{
@@ -32,6 +34,7 @@ impl Plugin for CombatPlugin {
         shot_fired::register_systems(app);
         on_collision_damage::register_systems(app);
         damage::register_systems(app);
+        heal::register_systems(app);
         death::register_systems(app);
         attached_to::register_systems(app);
     }
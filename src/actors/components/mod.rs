@@ -13,7 +13,7 @@ pub use combat::{
 pub use lifetime::Lifetime;
 pub use physics::{Collider, ColliderShape, DynamicBody, GameLayer, Size, StaticBody};
 pub use player::{
-    KeyboardMovement, MovementLocked, PlayerAbilityCooldowns, PlayerInput,
+    BeamCharge, KeyboardMovement, Mana, MovementLocked, PlayerAbilityCooldowns, PlayerInput,
 };
 pub use visual::{
     BobbingAnimation, CapsuleShape, CircleShape, Fade, FadeCollisionToggle,
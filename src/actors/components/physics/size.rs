@@ -30,7 +30,7 @@ fn init_scale_modifiers(
 
 fn sync_size_to_scale(
     layer: Res<SizeScaleLayer>,
-    mut query: Query<(&Size, &mut ScaleModifiers), Or<(Added<Size>, Added<ScaleModifiers>)>>,
+    mut query: Query<(&Size, &mut ScaleModifiers), Or<(Changed<Size>, Added<ScaleModifiers>)>>,
 ) {
     for (size, mut modifiers) in &mut query {
         modifiers.set(layer.0, Vec3::splat(size.value / 2.0));
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::schedule::GameSet;
+use crate::stats::{ComputedStats, Stat};
+use crate::wave::CombatPhase;
+
+#[derive(Component, Default)]
+pub struct Mana {
+    pub current: f32,
+}
+
+pub fn register_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        regenerate_mana
+            .before(GameSet::Input)
+            .run_if(in_state(CombatPhase::Running)),
+    );
+}
+
+fn regenerate_mana(time: Res<Time>, mut q: Query<(&mut Mana, &ComputedStats)>) {
+    let dt = time.delta_secs();
+    for (mut mana, stats) in &mut q {
+        let max = stats.final_of(Stat::MaxMana).max(0.0);
+        let regen = stats.final_of(Stat::ManaRegen);
+        mana.current = (mana.current + regen * dt).clamp(0.0, max);
+    }
+}
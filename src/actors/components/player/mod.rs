@@ -1,16 +1,22 @@
 use bevy::prelude::*;
 
 mod keyboard_movement;
+mod mana;
 mod player_input;
+mod primary_attack;
 
 pub use keyboard_movement::{KeyboardMovement, MovementLocked};
+pub use mana::Mana;
 pub use player_input::{PlayerAbilityCooldowns, PlayerInput};
+pub use primary_attack::BeamCharge;
 
 pub struct PlayerComponentsPlugin;
 
 impl Plugin for PlayerComponentsPlugin {
     fn build(&self, app: &mut App) {
         keyboard_movement::register_systems(app);
+        mana::register_systems(app);
         player_input::register_systems(app);
+        primary_attack::register_systems(app);
     }
 }
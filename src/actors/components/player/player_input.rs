@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 
-use super::super::super::player::{fire_fireball, FIREBALL_COOLDOWN};
+use super::super::super::player::{cast_nova, fire_fireball};
+use super::super::combat::{Health, PendingDamage};
+use super::primary_attack::{fire_beam, throw_knife, BeamCharge};
+use super::Mana;
+use crate::artifact::PrimaryAttack;
+use crate::balance::PlayerBalance;
 use crate::input::PlayerIntent;
 use crate::schedule::GameSet;
 use crate::stats::{ComputedStats, Stat};
@@ -13,12 +18,30 @@ pub struct PlayerInput;
 #[derive(Component, Default)]
 pub struct PlayerAbilityCooldowns {
     pub current: f32,
+    pub ability: f32,
 }
 
+type PrimaryCaster = (
+    Entity,
+    &'static Transform,
+    Option<&'static ComputedStats>,
+    &'static mut PlayerAbilityCooldowns,
+    Option<&'static PrimaryAttack>,
+    &'static mut BeamCharge,
+);
+
+type AbilityCaster = (
+    Entity,
+    &'static Transform,
+    &'static ComputedStats,
+    &'static mut PlayerAbilityCooldowns,
+    &'static mut Mana,
+);
+
 pub fn register_systems(app: &mut App) {
     app.add_systems(
         Update,
-        (tick_cooldowns, intent_fire_system)
+        (tick_cooldowns, intent_fire_system, intent_ability_system)
             .chain()
             .in_set(GameSet::Input)
             .run_if(in_state(CombatPhase::Running)),
@@ -29,45 +52,117 @@ fn tick_cooldowns(time: Res<Time>, mut q: Query<&mut PlayerAbilityCooldowns>) {
     let dt = time.delta_secs();
     for mut c in &mut q {
         c.current = (c.current - dt).max(0.0);
+        c.ability = (c.ability - dt).max(0.0);
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn intent_fire_system(
     mut commands: Commands,
+    time: Res<Time>,
     intent: Res<PlayerIntent>,
-    mut player_query: Query<
-        (
-            Entity,
-            &Transform,
-            Option<&ComputedStats>,
-            &mut PlayerAbilityCooldowns,
-        ),
-        With<PlayerInput>,
-    >,
+    player_balance: Res<PlayerBalance>,
+    mut player_query: Query<PrimaryCaster, With<PlayerInput>>,
+    targets: Query<(Entity, &Transform, &Faction), With<Health>>,
+    mut pending: MessageWriter<PendingDamage>,
 ) {
-    if !intent.fire {
-        return;
+    let aiming = intent.fire && intent.aim_dir != Vec2::ZERO;
+    for (player_entity, player_transform, stats, mut cooldowns, primary, mut charge) in
+        &mut player_query
+    {
+        let caster_pos = crate::coord::to_2d(player_transform.translation);
+        let attack_speed = stats
+            .map(|s| s.final_of(Stat::AttackSpeed))
+            .unwrap_or(1.0)
+            .max(0.01);
+        match primary.copied().unwrap_or_default() {
+            PrimaryAttack::Fireball => {
+                if !aiming || cooldowns.current > 0.0 {
+                    continue;
+                }
+                fire_fireball(
+                    &mut commands,
+                    player_entity,
+                    caster_pos,
+                    Faction::Player,
+                    intent.aim_dir,
+                    stats,
+                    &player_balance.fireball,
+                );
+                cooldowns.current = player_balance.fireball.cooldown / attack_speed;
+            }
+            PrimaryAttack::Knife { damage_pct, range, speed, cooldown } => {
+                if !aiming || cooldowns.current > 0.0 {
+                    continue;
+                }
+                throw_knife(
+                    &mut commands,
+                    player_entity,
+                    caster_pos,
+                    intent.aim_dir,
+                    stats,
+                    damage_pct,
+                    range,
+                    speed,
+                );
+                cooldowns.current = cooldown / attack_speed;
+            }
+            PrimaryAttack::Beam { damage_pct, charge: charge_time, length, width } => {
+                let full = (charge_time / attack_speed).max(0.01);
+                if aiming {
+                    charge.elapsed = (charge.elapsed + time.delta_secs()).min(full);
+                    charge.aim = intent.aim_dir;
+                }
+                if charge.elapsed <= 0.0 || (aiming && charge.elapsed < full) {
+                    continue;
+                }
+                let fraction = charge.elapsed / full;
+                charge.elapsed = 0.0;
+                let enemies = targets
+                    .iter()
+                    .filter(|(_, _, faction)| **faction != Faction::Player)
+                    .map(|(e, t, _)| (e, crate::coord::to_2d(t.translation)));
+                fire_beam(
+                    &mut commands,
+                    player_entity,
+                    caster_pos,
+                    charge.aim,
+                    stats,
+                    damage_pct,
+                    length,
+                    width,
+                    fraction,
+                    enemies,
+                    &mut pending,
+                );
+            }
+        }
     }
-    if intent.aim_dir == Vec2::ZERO {
+}
+
+fn intent_ability_system(
+    mut commands: Commands,
+    intent: Res<PlayerIntent>,
+    player_balance: Res<PlayerBalance>,
+    mut player_query: Query<AbilityCaster, With<PlayerInput>>,
+    targets: Query<(Entity, &Transform, &Faction), With<Health>>,
+    mut pending: MessageWriter<PendingDamage>,
+) {
+    if !intent.ability {
         return;
     }
-    for (player_entity, player_transform, stats, mut cooldowns) in &mut player_query {
-        if cooldowns.current > 0.0 {
+    let nova = &player_balance.nova;
+    for (player_entity, player_transform, stats, mut cooldowns, mut mana) in &mut player_query {
+        if cooldowns.ability > 0.0 || mana.current < nova.cost {
             continue;
         }
+        mana.current -= nova.cost;
+        cooldowns.ability = nova.cooldown;
         let caster_pos = crate::coord::to_2d(player_transform.translation);
-        fire_fireball(
-            &mut commands,
-            player_entity,
-            caster_pos,
-            Faction::Player,
-            intent.aim_dir,
-            stats,
-        );
-        let attack_speed = stats
-            .map(|s| s.final_of(Stat::AttackSpeed))
-            .unwrap_or(1.0)
-            .max(0.01);
-        cooldowns.current = FIREBALL_COOLDOWN / attack_speed;
+        let enemies = targets
+            .iter()
+            .filter(|(_, _, faction)| **faction != Faction::Player)
+            .map(|(e, t, _)| (e, crate::coord::to_2d(t.translation)));
+        cast_nova(&mut commands, player_entity, caster_pos, stats, nova, enemies, &mut pending);
     }
 }
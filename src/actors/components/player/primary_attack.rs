@@ -0,0 +1,283 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use super::super::combat::{Caster, OnCollisionDamage, PendingDamage};
+use super::super::physics::{Collider, ColliderShape, Size};
+use super::super::visual::{OnCollisionParticles, Shadow, Shape, ShapeColor, ShapeKind};
+use crate::artifact::OnHitEffectStack;
+use crate::palette;
+use crate::run::CombatScoped;
+use crate::schedule::GameSet;
+use crate::stats::{ComputedStats, Stat};
+use crate::wave::CombatPhase;
+use crate::Faction;
+
+const MIN_BEAM_CHARGE: f32 = 0.25;
+const BEAM_FLASH_DURATION: f32 = 0.15;
+const BEAM_HEIGHT: f32 = 30.0;
+const KNIFE_SIZE: f32 = 50.0;
+const KNIFE_SPREAD: f32 = 0.2;
+const KNIFE_CATCH_RADIUS: f32 = 60.0;
+
+#[derive(Component, Default)]
+pub struct BeamCharge {
+    pub elapsed: f32,
+    pub aim: Vec2,
+}
+
+#[derive(Component)]
+struct ReturningKnife {
+    range: f32,
+    speed: f32,
+    travelled: f32,
+    returning: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum KnifeStep {
+    Outbound,
+    Homing(Vec2),
+    Caught,
+}
+
+impl ReturningKnife {
+    /// Flies straight until `range` is covered, then homes back until within catch radius.
+    fn step(&mut self, dt: f32, pos: Vec2, caster_pos: Vec2) -> KnifeStep {
+        if !self.returning {
+            self.travelled += self.speed * dt;
+            self.returning = self.travelled >= self.range;
+            return KnifeStep::Outbound;
+        }
+        let to_caster = caster_pos - pos;
+        if to_caster.length() <= KNIFE_CATCH_RADIUS {
+            return KnifeStep::Caught;
+        }
+        KnifeStep::Homing(to_caster.normalize_or_zero() * self.speed)
+    }
+}
+
+#[derive(Component)]
+struct BeamFlash {
+    from: Vec3,
+    to: Vec3,
+    half_width: f32,
+    remaining: f32,
+}
+
+pub fn register_systems(app: &mut App) {
+    app.add_systems(
+        Update,
+        (update_returning_knives, draw_beam_flashes)
+            .in_set(GameSet::AbilityExecution)
+            .run_if(in_state(CombatPhase::Running)),
+    );
+}
+
+fn ability_color() -> ShapeColor {
+    let (r, g, b) = palette::lookup("player_ability").unwrap_or((0.5, 0.5, 1.0));
+    let flash = palette::flash_lookup("player_ability");
+    ShapeColor { r, g, b, a: 1.0, flash }
+}
+
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    p.distance(a + ab * t)
+}
+
+/// Damage scales with the charged `fraction`; too short a charge fizzles.
+#[allow(clippy::too_many_arguments)]
+pub fn fire_beam(
+    commands: &mut Commands,
+    caster: Entity,
+    caster_pos: Vec2,
+    direction: Vec2,
+    caster_stats: Option<&ComputedStats>,
+    damage_pct: f32,
+    length: f32,
+    width: f32,
+    fraction: f32,
+    targets: impl Iterator<Item = (Entity, Vec2)>,
+    pending: &mut MessageWriter<PendingDamage>,
+) {
+    if fraction < MIN_BEAM_CHARGE {
+        return;
+    }
+    let dir = direction.normalize_or_zero();
+    if dir == Vec2::ZERO {
+        return;
+    }
+    let area = caster_stats.map_or(1.0, |s| s.scale_of(Stat::AreaOfEffect));
+    let half_width = width * area / 2.0;
+    let end = caster_pos + dir * length * area;
+    let damage = caster_stats.map_or(0.0, |s| s.final_of(Stat::PhysicalDamage))
+        * damage_pct
+        * fraction;
+    let on_hit = OnHitEffectStack::from_stats(caster_stats);
+    for (target, pos) in targets {
+        if distance_to_segment(pos, caster_pos, end) <= half_width {
+            pending.write(PendingDamage {
+                target,
+                amount: damage,
+                source: Some(caster),
+                on_hit,
+            });
+        }
+    }
+    commands.spawn((
+        Name::new("BeamFlash"),
+        BeamFlash {
+            from: crate::coord::ground_pos(caster_pos) + Vec3::Y * BEAM_HEIGHT,
+            to: crate::coord::ground_pos(end) + Vec3::Y * BEAM_HEIGHT,
+            half_width,
+            remaining: BEAM_FLASH_DURATION,
+        },
+        CombatScoped,
+    ));
+}
+
+/// Throws knives that fly out to `range` and come back to the caster, hitting along both legs.
+#[allow(clippy::too_many_arguments)]
+pub fn throw_knife(
+    commands: &mut Commands,
+    caster: Entity,
+    caster_pos: Vec2,
+    direction: Vec2,
+    caster_stats: Option<&ComputedStats>,
+    damage_pct: f32,
+    range: f32,
+    base_speed: f32,
+) {
+    let dir = direction.normalize_or_zero();
+    if dir == Vec2::ZERO {
+        return;
+    }
+    let count = 1 + caster_stats
+        .map(|s| s.final_of(Stat::ProjectileCount))
+        .unwrap_or(0.0)
+        .max(0.0) as u32;
+    let speed = caster_stats.map_or(base_speed, |s| s.apply(Stat::ProjectileSpeed, base_speed));
+    let damage = caster_stats.map_or(0.0, |s| s.final_of(Stat::PhysicalDamage)) * damage_pct;
+    let on_hit = OnHitEffectStack::from_stats(caster_stats);
+
+    for i in 0..count {
+        let angle = KNIFE_SPREAD * (i as f32 - (count as f32 - 1.0) / 2.0);
+        let velocity = Vec2::from_angle(angle).rotate(dir) * speed;
+        let knife = commands
+            .spawn((
+                Name::new("ReturningKnife"),
+                Transform::from_translation(crate::coord::ground_pos(caster_pos)),
+                Visibility::default(),
+                Faction::Player,
+                Caster(caster),
+                Size { value: KNIFE_SIZE },
+                Collider { shape: ColliderShape::Circle, sensor: true },
+                RigidBody::Kinematic,
+                LockedAxes::ROTATION_LOCKED.lock_translation_y(),
+                LinearVelocity(crate::coord::ground_vel(velocity)),
+                OnCollisionDamage { amount: damage },
+                OnCollisionParticles { config: "hit_burst" },
+                ReturningKnife {
+                    range,
+                    speed,
+                    travelled: 0.0,
+                    returning: false,
+                },
+                CombatScoped,
+            ))
+            .id();
+        if !on_hit.is_empty() {
+            commands.entity(knife).insert(on_hit);
+        }
+        commands.entity(knife).with_children(|p| {
+            p.spawn(Shadow);
+            p.spawn(Shape {
+                color: ability_color(),
+                kind: ShapeKind::Capsule,
+                position: Vec2::ZERO,
+                elevation: 1.5,
+                half_length: 0.5,
+            });
+        });
+    }
+}
+
+fn update_returning_knives(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut knives: Query<(Entity, &Transform, &Caster, &mut ReturningKnife, &mut LinearVelocity)>,
+    casters: Query<&Transform, Without<ReturningKnife>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, transform, caster, mut knife, mut velocity) in &mut knives {
+        let Ok(caster_t) = casters.get(caster.0) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let pos = crate::coord::to_2d(transform.translation);
+        match knife.step(dt, pos, crate::coord::to_2d(caster_t.translation)) {
+            KnifeStep::Outbound => {}
+            KnifeStep::Homing(v) => velocity.0 = crate::coord::ground_vel(v),
+            KnifeStep::Caught => commands.entity(entity).despawn(),
+        }
+    }
+}
+
+fn draw_beam_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut flashes: Query<(Entity, &mut BeamFlash)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut flash) in &mut flashes {
+        flash.remaining -= dt;
+        if flash.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = (flash.remaining / BEAM_FLASH_DURATION).clamp(0.0, 1.0);
+        let color = palette::color_alpha("player_ability", alpha);
+        let side = (flash.to - flash.from).cross(Vec3::Y).normalize_or_zero() * flash.half_width;
+        for offset in [Vec3::ZERO, side, -side] {
+            gizmos.line(flash.from + offset, flash.to + offset, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beam_hits_by_distance_to_the_segment() {
+        let (a, b) = (Vec2::ZERO, Vec2::new(100.0, 0.0));
+        assert_eq!(distance_to_segment(Vec2::new(50.0, 20.0), a, b), 20.0);
+        assert_eq!(distance_to_segment(Vec2::new(-30.0, 40.0), a, b), 50.0);
+        assert_eq!(distance_to_segment(Vec2::new(130.0, 0.0), a, b), 30.0);
+        assert_eq!(distance_to_segment(Vec2::new(3.0, 4.0), a, a), 5.0);
+    }
+
+    #[test]
+    fn knife_flies_out_then_homes_back_and_is_caught() {
+        let mut knife = ReturningKnife {
+            range: 100.0,
+            speed: 50.0,
+            travelled: 0.0,
+            returning: false,
+        };
+        let caster = Vec2::ZERO;
+        assert_eq!(knife.step(1.0, Vec2::new(50.0, 0.0), caster), KnifeStep::Outbound);
+        assert_eq!(knife.step(1.0, Vec2::new(100.0, 0.0), caster), KnifeStep::Outbound);
+        assert!(knife.returning);
+        assert_eq!(
+            knife.step(1.0, Vec2::new(100.0, 0.0), caster),
+            KnifeStep::Homing(Vec2::new(-50.0, 0.0))
+        );
+        assert_eq!(knife.step(1.0, Vec2::new(KNIFE_CATCH_RADIUS, 0.0), caster), KnifeStep::Caught);
+    }
+}
@@ -2,18 +2,15 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 
 use crate::GameState;
-use crate::balance::MobCommonStats;
+use crate::balance::{GhostBehaviour, MobBalance};
 use super::super::components::{
     BobbingAnimation, Fade, FadeCollisionToggle, MeleeAttacker, SelfMoving, Shape, ShapeKind,
 };
 use super::super::player::Player;
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, ModifierKind, Stat, StatCalculators};
+use crate::stats::{ComputedStats, Stat};
 
-use super::spawn::{enemy_shape_color, spawn_enemy_core, EnemyBody, WaveModifiers};
-
-pub const GHOST_VISIBLE_DISTANCE: f32 = 150.0;
-pub const GHOST_INVISIBLE_DISTANCE: f32 = 400.0;
+use super::spawn::{MobStats, enemy_shape_color, spawn_enemy_core, EnemyBody, MobKind, WaveModifiers};
 
 #[derive(Component)]
 pub struct GhostTransparency {
@@ -44,22 +41,18 @@ pub fn register_systems(app: &mut App) {
 pub fn spawn_ghost(
     commands: &mut Commands,
     pos: Vec2,
-    s: &MobCommonStats,
-    calculators: &StatCalculators,
+    m: &MobBalance<GhostBehaviour>,
+    stats: MobStats,
     wave_mods: WaveModifiers,
 ) -> Entity {
-    let speed = s.speed.unwrap_or(0.0);
+    let s = &m.stats;
     let mass = s.mass.unwrap_or(1.0);
     let id = spawn_enemy_core(
         commands,
         pos,
-        calculators,
-        &[
-            (Stat::MovementSpeed, ModifierKind::Flat, speed),
-            (Stat::MaxLife, ModifierKind::Flat, s.hp),
-            (Stat::PhysicalDamage, ModifierKind::Flat, s.damage),
-        ],
-        s.size,
+        stats,
+        MobKind::Ghost,
+        s,
         EnemyBody::Dynamic { mass },
         "enemy_death",
         wave_mods,
@@ -67,8 +60,8 @@ pub fn spawn_ghost(
 
     commands.entity(id).insert((
         GhostTransparency {
-            visible_distance: GHOST_VISIBLE_DISTANCE,
-            invisible_distance: GHOST_INVISIBLE_DISTANCE,
+            visible_distance: m.behaviour.visible_distance,
+            invisible_distance: m.behaviour.invisible_distance,
         },
         MoveToward {},
         MeleeAttacker::new(s.attack_speed.unwrap_or(1.0)),
@@ -2,7 +2,7 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::balance::MobCommonStats;
+use crate::balance::{JumperBehaviour, MobBalance};
 use super::super::components::{
     Caster, Collider, ColliderShape, JumpWalkAnimation, Lifetime, OnCollisionDamage,
     OnCollisionParticles, Projectile, SelfMoving, Shadow, Shape, ShapeKind, Size,
@@ -11,20 +11,17 @@ use crate::arena::CurrentArenaSize;
 use crate::faction::Faction;
 use crate::run::CombatScoped;
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, ModifierKind, Stat, StatCalculators};
+use crate::stats::{ComputedStats, Stat};
 
-use super::spawn::{enemy_ability_shape_color, enemy_shape_color, spawn_enemy_core, EnemyBody, WaveModifiers};
+use super::spawn::{MobStats, enemy_ability_shape_color, enemy_shape_color, spawn_enemy_core, EnemyBody, MobKind, WaveModifiers};
 
 const JUMPER_SHOT_DAMAGE_PCT: f32 = 1.0;
 
 const JUMPER_IDLE_WEIGHT: f32 = 75.0;
 const JUMPER_JUMP_WEIGHT: f32 = 12.5;
 const JUMPER_LAND_WEIGHT: f32 = 12.5;
-const JUMPER_PROJECTILE_COUNT: u32 = 4;
-const JUMPER_PROJECTILE_SPEED: f32 = 400.0;
 const JUMPER_PROJECTILE_SIZE: f32 = 60.0;
 const JUMPER_PROJECTILE_LIFETIME: f32 = 3.0;
-const JUMPER_SPREAD_DEGREES: f32 = 45.0;
 
 #[derive(Component)]
 pub struct JumperAi {
@@ -80,10 +77,11 @@ pub fn register_systems(app: &mut App) {
 pub fn spawn_jumper(
     commands: &mut Commands,
     pos: Vec2,
-    s: &MobCommonStats,
-    calculators: &StatCalculators,
+    m: &MobBalance<JumperBehaviour>,
+    stats: MobStats,
     wave_mods: WaveModifiers,
 ) -> Entity {
+    let s = &m.stats;
     let speed = s.speed.unwrap_or(0.0);
     let mass = s.mass.unwrap_or(1.0);
     let attack_speed = s.attack_speed.unwrap_or(4.0);
@@ -92,13 +90,9 @@ pub fn spawn_jumper(
     let id = spawn_enemy_core(
         commands,
         pos,
-        calculators,
-        &[
-            (Stat::MovementSpeed, ModifierKind::Flat, speed),
-            (Stat::MaxLife, ModifierKind::Flat, s.hp),
-            (Stat::PhysicalDamage, ModifierKind::Flat, s.damage),
-        ],
-        s.size,
+        stats,
+        MobKind::Jumper,
+        s,
         EnemyBody::Dynamic { mass },
         "enemy_death_large",
         wave_mods,
@@ -109,11 +103,11 @@ pub fn spawn_jumper(
         jump_duration: phase(JUMPER_JUMP_WEIGHT),
         land_duration: phase(JUMPER_LAND_WEIGHT),
         jump_speed: speed,
-        projectile_count: JUMPER_PROJECTILE_COUNT,
-        projectile_speed: JUMPER_PROJECTILE_SPEED,
+        projectile_count: m.behaviour.projectile_count,
+        projectile_speed: m.behaviour.projectile_speed,
         projectile_size: JUMPER_PROJECTILE_SIZE,
         projectile_lifetime: JUMPER_PROJECTILE_LIFETIME,
-        spread_degrees: JUMPER_SPREAD_DEGREES,
+        spread_degrees: m.behaviour.spread_degrees,
     });
 
     commands.entity(id).with_children(|p| {
@@ -160,8 +154,12 @@ fn jumper_ai_system(
                     state.phase = JumperPhase::Jump;
                     state.elapsed = 0.0;
                     state.ability_fired = false;
+                    let speed = stats_q
+                        .get(entity)
+                        .map(|s| s.final_of(Stat::MovementSpeed))
+                        .unwrap_or(ai.jump_speed);
                     commands.entity(entity).insert(RandomJump {
-                        speed: ai.jump_speed,
+                        speed,
                         duration: ai.jump_duration,
                     });
                 }
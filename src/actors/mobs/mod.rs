@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::balance::MobsBalance;
+use crate::stats::StatCalculators;
+
 pub mod ghost;
 mod jumper;
 mod slime;
@@ -8,7 +11,7 @@ mod spinner;
 mod tower;
 
 pub use ghost::GhostTransparency;
-pub use spawn::{spawn_mob, MobKind, WaveModifiers};
+pub use spawn::{spawn_mob, Mob, MobKind, MobStatTemplates, WaveModifiers};
 
 pub struct MobsPlugin;
 
@@ -19,5 +22,13 @@ impl Plugin for MobsPlugin {
         tower::register_systems(app);
         jumper::register_systems(app);
         spinner::register_systems(app);
+        app.init_resource::<MobStatTemplates>().add_systems(
+            Update,
+            (
+                spawn::rebuild_mob_templates
+                    .run_if(resource_changed::<MobsBalance>.or(resource_changed::<StatCalculators>)),
+                spawn::reapply_mob_balance.run_if(resource_changed::<MobsBalance>),
+            ),
+        );
     }
 }
@@ -1,19 +1,17 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
-use crate::balance::MobCommonStats;
+use crate::balance::{MobBalance, SlimeBehaviour};
 use super::super::components::{
     JumpWalkAnimation, MeleeAttacker, SelfMoving, Shape, ShapeKind,
 };
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, ModifierKind, Stat, StatCalculators};
+use crate::stats::{ComputedStats, Stat};
 
-use super::spawn::{enemy_shape_color, spawn_enemy_core, EnemyBody, WaveModifiers};
+use super::spawn::{MobStats, enemy_shape_color, spawn_enemy_core, EnemyBody, MobKind, WaveModifiers};
 
 const LUNGE_DEFAULT_DURATION: f32 = 0.6;
 
-const SLIME_LUNGE_DURATION: f32 = 0.5;
-
 #[derive(Component)]
 pub struct LungeMovement {
     pub speed: Option<f32>,
@@ -52,29 +50,25 @@ pub fn register_systems(app: &mut App) {
 pub fn spawn_slime_small(
     commands: &mut Commands,
     pos: Vec2,
-    s: &MobCommonStats,
-    calculators: &StatCalculators,
+    m: &MobBalance<SlimeBehaviour>,
+    stats: MobStats,
     wave_mods: WaveModifiers,
 ) -> Entity {
-    let speed = s.speed.unwrap_or(0.0);
+    let s = &m.stats;
     let mass = s.mass.unwrap_or(1.0);
     let id = spawn_enemy_core(
         commands,
         pos,
-        calculators,
-        &[
-            (Stat::MovementSpeed, ModifierKind::Flat, speed),
-            (Stat::MaxLife, ModifierKind::Flat, s.hp),
-            (Stat::PhysicalDamage, ModifierKind::Flat, s.damage),
-        ],
-        s.size,
+        stats,
+        MobKind::SlimeSmall,
+        s,
         EnemyBody::Dynamic { mass },
         "enemy_death",
         wave_mods,
     );
 
     commands.entity(id).insert((
-        LungeMovement { speed: None, duration: Some(SLIME_LUNGE_DURATION), pause_duration: 0.4, distance: None },
+        LungeMovement { speed: None, duration: Some(m.behaviour.lunge_duration), pause_duration: 0.4, distance: None },
         MeleeAttacker::new(s.attack_speed.unwrap_or(0.5)),
     ));
 
@@ -1,11 +1,15 @@
 use avian3d::prelude::CollidingEntities;
 use bevy::prelude::*;
+use std::collections::HashMap;
 use strum::IntoEnumIterator;
 
-use crate::balance::MobsBalance;
+use crate::balance::{MobCommonStats, MobsBalance};
 use crate::faction::Faction;
 use crate::palette;
-use crate::stats::{ComputedStats, DirtyStats, ModifierKind, Modifiers, Stat, StatCalculators};
+use crate::stats::{
+    ComputedStats, DirtyStats, ModifierKind, ModifierSource, Modifiers, Stat, StatCalculators,
+    StatTemplate,
+};
 
 use super::super::components::{
     Caster, Collider, ColliderShape, DynamicBody, Health, OnDeathParticles, Shadow, ShapeColor,
@@ -38,6 +42,12 @@ impl Default for WaveModifiers {
     }
 }
 
+#[derive(Component, Copy, Clone, Debug)]
+pub struct Mob {
+    pub kind: MobKind,
+    pub wave_mods: WaveModifiers,
+}
+
 impl MobKind {
     pub fn id(self) -> &'static str {
         self.into()
@@ -47,13 +57,44 @@ impl MobKind {
         <Self as IntoEnumIterator>::iter()
     }
 
+    pub fn base_stats(self, s: &MobCommonStats) -> Vec<(Stat, ModifierKind, f32)> {
+        let mut out = Vec::new();
+        let speed = match self {
+            MobKind::Ghost | MobKind::SlimeSmall | MobKind::Jumper => Some(s.speed.unwrap_or(0.0)),
+            MobKind::Spinner => Some(s.speed.unwrap_or(spinner::DEFAULT_CHARGE_SPEED)),
+            MobKind::Tower => None,
+        };
+        if let Some(speed) = speed {
+            out.push((Stat::MovementSpeed, ModifierKind::Flat, speed));
+        }
+        out.push((Stat::MaxLife, ModifierKind::Flat, s.hp));
+        out.push((Stat::PhysicalDamage, ModifierKind::Flat, s.damage));
+        out
+    }
+
     pub fn size(&self, mobs: &MobsBalance) -> f32 {
-        match self {
-            MobKind::Ghost => mobs.ghost.size,
-            MobKind::Tower => mobs.tower.size,
-            MobKind::SlimeSmall => mobs.slime_small.size,
-            MobKind::Spinner => mobs.spinner.size,
-            MobKind::Jumper => mobs.jumper.size,
+        mobs.get(*self).size
+    }
+}
+
+pub(crate) type MobStats = (Modifiers, DirtyStats, ComputedStats);
+
+#[derive(Resource, Default)]
+pub struct MobStatTemplates(HashMap<MobKind, StatTemplate>);
+
+impl MobStatTemplates {
+    fn instantiate(
+        &self,
+        kind: MobKind,
+        mobs: &MobsBalance,
+        calculators: &StatCalculators,
+        wave_mods: WaveModifiers,
+    ) -> MobStats {
+        let scaling = wave_scaling(wave_mods);
+        match self.0.get(&kind) {
+            Some(template) => template.instantiate(calculators, ModifierSource::WaveScaling, scaling),
+            None => StatTemplate::build(calculators, kind.base_stats(mobs.get(kind)))
+                .instantiate(calculators, ModifierSource::WaveScaling, scaling),
         }
     }
 }
@@ -64,14 +105,16 @@ pub fn spawn_mob(
     pos: Vec2,
     mobs: &MobsBalance,
     calculators: &StatCalculators,
+    templates: &MobStatTemplates,
     wave_mods: WaveModifiers,
 ) -> Entity {
+    let stats = templates.instantiate(kind, mobs, calculators, wave_mods);
     match kind {
-        MobKind::Ghost => ghost::spawn_ghost(commands, pos, &mobs.ghost, calculators, wave_mods),
-        MobKind::Tower => tower::spawn_tower(commands, pos, &mobs.tower, calculators, wave_mods),
-        MobKind::SlimeSmall => slime::spawn_slime_small(commands, pos, &mobs.slime_small, calculators, wave_mods),
-        MobKind::Spinner => spinner::spawn_spinner(commands, pos, &mobs.spinner, calculators, wave_mods),
-        MobKind::Jumper => jumper::spawn_jumper(commands, pos, &mobs.jumper, calculators, wave_mods),
+        MobKind::Ghost => ghost::spawn_ghost(commands, pos, &mobs.ghost, stats, wave_mods),
+        MobKind::Tower => tower::spawn_tower(commands, pos, &mobs.tower, stats, wave_mods),
+        MobKind::SlimeSmall => slime::spawn_slime_small(commands, pos, &mobs.slime_small, stats, wave_mods),
+        MobKind::Spinner => spinner::spawn_spinner(commands, pos, &mobs.spinner, stats, wave_mods),
+        MobKind::Jumper => jumper::spawn_jumper(commands, pos, &mobs.jumper, stats, wave_mods),
     }
 }
 
@@ -95,17 +138,15 @@ pub(crate) enum EnemyBody {
 pub(crate) fn spawn_enemy_core(
     commands: &mut Commands,
     pos: Vec2,
-    calculators: &StatCalculators,
-    base_stats: &[(Stat, ModifierKind, f32)],
-    size: f32,
+    stats: MobStats,
+    kind: MobKind,
+    s: &MobCommonStats,
     body: EnemyBody,
     death_particles: &'static str,
     wave_mods: WaveModifiers,
 ) -> Entity {
-    let mut all_mods: Vec<(Stat, ModifierKind, f32)> = base_stats.to_vec();
-    all_mods.push((Stat::MaxLife, ModifierKind::More, wave_mods.hp_mult - 1.0));
-    all_mods.push((Stat::PhysicalDamage, ModifierKind::More, wave_mods.damage_mult - 1.0));
-    let (modifiers, dirty, computed) = build_stats(calculators, &all_mods);
+    let mob = Mob { kind, wave_mods };
+    let (modifiers, dirty, computed) = stats;
     let hp = computed.final_of(Stat::MaxLife);
     let ground = crate::coord::ground_pos(pos);
 
@@ -114,8 +155,9 @@ pub(crate) fn spawn_enemy_core(
         Transform::from_translation(ground),
         Visibility::default(),
         Faction::Enemy,
+        mob,
         modifiers, dirty, computed,
-        Size { value: size },
+        Size { value: s.size },
         Collider { shape: ColliderShape::Circle, sensor: false },
         CollidingEntities::default(),
         Health { current: hp },
@@ -139,17 +181,78 @@ pub(crate) fn spawn_enemy_core(
     id
 }
 
-fn build_stats(
+fn wave_scaling(wave_mods: WaveModifiers) -> [(Stat, ModifierKind, f32); 2] {
+    [
+        (Stat::MaxLife, ModifierKind::More, wave_mods.hp_mult - 1.0),
+        (Stat::PhysicalDamage, ModifierKind::More, wave_mods.damage_mult - 1.0),
+    ]
+}
+
+pub(super) fn rebuild_mob_templates(
+    mobs: Res<MobsBalance>,
+    calculators: Res<StatCalculators>,
+    mut templates: ResMut<MobStatTemplates>,
+) {
+    templates.0 = MobKind::iter()
+        .map(|kind| (kind, StatTemplate::build(&calculators, kind.base_stats(mobs.get(kind)))))
+        .collect();
+}
+
+pub(super) fn reapply_mob_balance(
+    mobs: Res<MobsBalance>,
+    calculators: Res<StatCalculators>,
+    mut query: Query<(&Mob, &mut Modifiers, &mut ComputedStats, &mut DirtyStats, &mut Health)>,
+) {
+    for (mob, mut modifiers, mut computed, mut dirty, mut health) in &mut query {
+        rebase_stats(
+            mob.kind.base_stats(mobs.get(mob.kind)),
+            &calculators,
+            &mut modifiers,
+            &mut computed,
+            &mut dirty,
+            &mut health,
+        );
+    }
+}
+
+/// Swaps only the `Base` modifiers and keeps the current life fraction of the new MaxLife.
+fn rebase_stats(
+    base: Vec<(Stat, ModifierKind, f32)>,
     calculators: &StatCalculators,
-    base_stats: &[(Stat, ModifierKind, f32)],
-) -> (Modifiers, DirtyStats, ComputedStats) {
-    let mut modifiers = Modifiers::new();
-    for &(stat, kind, value) in base_stats {
-        modifiers.add(stat, kind, value);
+    modifiers: &mut Modifiers,
+    computed: &mut ComputedStats,
+    dirty: &mut DirtyStats,
+    health: &mut Health,
+) {
+    let old_max = computed.final_of(Stat::MaxLife);
+    let fraction = if old_max > 0.0 { health.current / old_max } else { 1.0 };
+    modifiers.replace_source(ModifierSource::Base, base);
+    dirty.mark_all(modifiers.take_changed());
+    calculators.recalculate(modifiers, computed, dirty);
+    health.current = fraction * computed.final_of(Stat::MaxLife);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebase_keeps_life_fraction_and_wave_scaling() {
+        let calculators = StatCalculators::build();
+        let mut modifiers = Modifiers::new();
+        modifiers.add(Stat::MaxLife, ModifierKind::Flat, 100.0);
+        modifiers.add_from(ModifierSource::WaveScaling, Stat::MaxLife, ModifierKind::More, 1.0);
+        let mut computed = ComputedStats::default();
+        let mut dirty = DirtyStats::default();
+        dirty.mark_all(modifiers.take_changed());
+        calculators.recalculate(&modifiers, &mut computed, &mut dirty);
+        assert_eq!(computed.final_of(Stat::MaxLife), 200.0);
+
+        let mut health = Health { current: 50.0 };
+        let base = vec![(Stat::MaxLife, ModifierKind::Flat, 40.0)];
+        rebase_stats(base, &calculators, &mut modifiers, &mut computed, &mut dirty, &mut health);
+        assert_eq!(computed.final_of(Stat::MaxLife), 80.0);
+        assert_eq!(health.current, 20.0);
+        assert!(modifiers.iter().any(|m| m.source == ModifierSource::WaveScaling));
     }
-    let mut dirty = DirtyStats::default();
-    let mut computed = ComputedStats::default();
-    dirty.mark_all(Stat::iter());
-    calculators.recalculate(&modifiers, &mut computed, &mut dirty);
-    (modifiers, dirty, computed)
 }
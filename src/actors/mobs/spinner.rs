@@ -3,7 +3,7 @@ use std::f32::consts::{FRAC_PI_2, TAU};
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
-use crate::balance::MobCommonStats;
+use crate::balance::{MobBalance, SpinnerBehaviour};
 use super::super::components::{
     CircleShape, GameLayer, PendingDamage, SelfMoving, Size, Shape, ShapeKind,
 };
@@ -12,9 +12,9 @@ use crate::faction::Faction;
 use crate::palette;
 use crate::particles;
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, ModifierKind, Stat, StatCalculators};
+use crate::stats::{ComputedStats, Stat};
 
-use super::spawn::{enemy_shape_color, spawn_enemy_core, EnemyBody, WaveModifiers};
+use super::spawn::{MobStats, enemy_shape_color, spawn_enemy_core, EnemyBody, MobKind, WaveModifiers};
 
 const SPIKE_COUNT: usize = 6;
 const SPIKE_OFFSET: f32 = 0.55;
@@ -25,15 +25,10 @@ const TRAIL_THRESHOLD: f32 = 0.5;
 const MAX_SPIN_SPEED: f32 = 10.0;
 const SPIKE_GROWTH_MAX: f32 = 3.0;
 const SQUISH_MIN: f32 = 0.5;
-const CONTACT_RADIUS: f32 = 150.0;
 const HIT_RADIUS: f32 = 150.0;
 const SPIN_HIT_DAMAGE_PCT: f32 = 1.0;
 
 const SPINNER_SPIKE_LENGTH: f32 = 1.0;
-const SPINNER_IDLE_WEIGHT: f32 = 10.0;
-const SPINNER_WINDUP_WEIGHT: f32 = 60.0;
-const SPINNER_CHARGE_WEIGHT: f32 = 20.0;
-const SPINNER_COOLDOWN_WEIGHT: f32 = 10.0;
 
 #[derive(Clone, Copy, PartialEq)]
 enum SpinnerPhase {
@@ -51,6 +46,7 @@ pub struct Spinner {
     pub cooldown_duration: f32,
     pub charge_speed: f32,
     pub spike_length: f32,
+    pub contact_radius: f32,
 
     phase: SpinnerPhase,
     elapsed: f32,
@@ -68,6 +64,8 @@ pub struct Spinner {
 #[derive(Resource)]
 pub struct SpinnerSquishScaleLayer(pub ScaleLayerId);
 
+pub(super) const DEFAULT_CHARGE_SPEED: f32 = 1200.0;
+
 pub fn register_systems(app: &mut App) {
     app.add_systems(Startup, register_scale_layer);
     app.add_systems(Update, spinner_tick.in_set(GameSet::MobAI));
@@ -82,39 +80,36 @@ fn register_scale_layer(mut registry: ResMut<ScaleLayerRegistry>, mut commands:
 pub fn spawn_spinner(
     commands: &mut Commands,
     pos: Vec2,
-    s: &MobCommonStats,
-    calculators: &StatCalculators,
+    m: &MobBalance<SpinnerBehaviour>,
+    stats: MobStats,
     wave_mods: WaveModifiers,
 ) -> Entity {
+    let s = &m.stats;
     let mass = s.mass.unwrap_or(1.0);
-    let charge_speed = s.speed.unwrap_or(1200.0);
+    let charge_speed = s.speed.unwrap_or(DEFAULT_CHARGE_SPEED);
     let attack_speed = s.attack_speed.unwrap_or(5.0);
-    let total_weight = SPINNER_IDLE_WEIGHT
-        + SPINNER_WINDUP_WEIGHT
-        + SPINNER_CHARGE_WEIGHT
-        + SPINNER_COOLDOWN_WEIGHT;
+    let b = &m.behaviour;
+    let total_weight = b.idle_weight + b.windup_weight + b.charge_weight + b.cooldown_weight;
     let phase = |w: f32| attack_speed * w / total_weight;
     let id = spawn_enemy_core(
         commands,
         pos,
-        calculators,
-        &[
-            (Stat::MaxLife, ModifierKind::Flat, s.hp),
-            (Stat::PhysicalDamage, ModifierKind::Flat, s.damage),
-        ],
-        s.size,
+        stats,
+        MobKind::Spinner,
+        s,
         EnemyBody::Dynamic { mass },
         "enemy_death_large",
         wave_mods,
     );
 
     commands.entity(id).insert(Spinner {
-        idle_duration: phase(SPINNER_IDLE_WEIGHT),
-        windup_duration: phase(SPINNER_WINDUP_WEIGHT),
-        charge_duration: phase(SPINNER_CHARGE_WEIGHT),
-        cooldown_duration: phase(SPINNER_COOLDOWN_WEIGHT),
+        idle_duration: phase(b.idle_weight),
+        windup_duration: phase(b.windup_weight),
+        charge_duration: phase(b.charge_weight),
+        cooldown_duration: phase(b.cooldown_weight),
         charge_speed,
         spike_length: SPINNER_SPIKE_LENGTH,
+        contact_radius: b.contact_radius,
         phase: SpinnerPhase::Idle,
         elapsed: 0.0,
         spin_angle: 0.0,
@@ -193,7 +188,7 @@ fn spinner_tick(
                             entity,
                             transform,
                             size,
-                            CONTACT_RADIUS,
+                            spinner.contact_radius,
                             &stats_query,
                             &spatial_query,
                             &mut pending,
@@ -224,10 +219,14 @@ fn spinner_tick(
                         GameLayer::Enemy,
                         [GameLayer::Wall, GameLayer::PlayerProjectile],
                     );
+                    let charge_speed = stats_query
+                        .get(entity)
+                        .map(|s| s.final_of(Stat::MovementSpeed))
+                        .unwrap_or(spinner.charge_speed);
                     commands.entity(entity).insert((
                         charge_layers,
                         SelfMoving,
-                        LinearVelocity(crate::coord::ground_vel(direction * spinner.charge_speed)),
+                        LinearVelocity(crate::coord::ground_vel(direction * charge_speed)),
                     ));
                 }
             }
@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use rand::Rng;
 
 use crate::GameState;
-use crate::balance::MobCommonStats;
+use crate::balance::{MobBalance, TowerBehaviour};
 use super::super::components::{
     CircleShape, Growing, Lifetime, PendingDamage, ScaleOut, Shadow, ShootSquish, ShotFired, Size,
     Shape, ShapeColor, ShapeKind,
@@ -13,16 +13,14 @@ use crate::palette;
 use crate::particles;
 use crate::run::CombatScoped;
 use crate::schedule::GameSet;
-use crate::stats::{ComputedStats, ModifierKind, Stat, StatCalculators};
+use crate::stats::{ComputedStats, Stat};
 
-use super::spawn::{enemy_shape_color, spawn_enemy_core, EnemyBody, WaveModifiers};
+use super::spawn::{MobStats, enemy_shape_color, spawn_enemy_core, EnemyBody, MobKind, WaveModifiers};
 
 const TOWER_FLIGHT_DURATION: f32 = 0.8;
 const TOWER_ARC_HEIGHT: f32 = 8.0;
 const TOWER_START_ELEVATION: f32 = 1.6;
-const TOWER_SPREAD: f32 = 450.0;
 const TOWER_PROJECTILE_SIZE: f32 = 60.0;
-const TOWER_EXPLOSION_RADIUS: f32 = 400.0;
 const TOWER_EXPLOSION_DURATION: f32 = 0.5;
 const TOWER_INDICATOR_DURATION: f32 = 0.8;
 
@@ -107,19 +105,17 @@ fn init_tower_visual(
 pub fn spawn_tower(
     commands: &mut Commands,
     pos: Vec2,
-    s: &MobCommonStats,
-    calculators: &StatCalculators,
+    m: &MobBalance<TowerBehaviour>,
+    stats: MobStats,
     wave_mods: WaveModifiers,
 ) -> Entity {
+    let s = &m.stats;
     let id = spawn_enemy_core(
         commands,
         pos,
-        calculators,
-        &[
-            (Stat::MaxLife, ModifierKind::Flat, s.hp),
-            (Stat::PhysicalDamage, ModifierKind::Flat, s.damage),
-        ],
-        s.size,
+        stats,
+        MobKind::Tower,
+        s,
         EnemyBody::Static,
         "enemy_death_large",
         wave_mods,
@@ -134,9 +130,9 @@ pub fn spawn_tower(
             flight_duration: TOWER_FLIGHT_DURATION,
             arc_height: TOWER_ARC_HEIGHT,
             start_elevation: TOWER_START_ELEVATION,
-            spread: TOWER_SPREAD,
+            spread: m.behaviour.spread,
             projectile_size: TOWER_PROJECTILE_SIZE,
-            explosion_radius: TOWER_EXPLOSION_RADIUS,
+            explosion_radius: m.behaviour.explosion_radius,
             explosion_duration: TOWER_EXPLOSION_DURATION,
             indicator_duration: TOWER_INDICATOR_DURATION,
         },
@@ -6,9 +6,9 @@ pub mod player;
 
 pub use components::{
     death_system, CapsuleShape, CircleShape, DeathEvent, Fade, GameLayer,
-    Health, JumpWalkAnimationState, MovementLocked, Shape, SkipCleanup,
+    Health, JumpWalkAnimationState, Mana, MovementLocked, Shape, SkipCleanup,
 };
-pub use mobs::{spawn_mob, GhostTransparency, MobKind, WaveModifiers};
+pub use mobs::{spawn_mob, GhostTransparency, Mob, MobKind, MobStatTemplates, WaveModifiers};
 pub use player::Player;
 
 pub struct ActorsPlugin;
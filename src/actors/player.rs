@@ -2,25 +2,20 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 
 use super::components::{
-    Caster, Collider, ColliderShape, DynamicBody, Health, JumpWalkAnimation, KeyboardMovement,
-    OnCollisionDamage, OnCollisionParticles, PlayerAbilityCooldowns, PlayerInput, Projectile,
-    Shadow, Shape, ShapeColor, ShapeKind, Size,
+    BeamCharge, Caster, Collider, ColliderShape, DynamicBody, Health, JumpWalkAnimation,
+    KeyboardMovement, Mana, OnCollisionDamage, OnCollisionParticles, PendingDamage,
+    PlayerAbilityCooldowns, PlayerInput, Projectile, Shadow, Shape, ShapeColor, ShapeKind, Size,
 };
 use crate::artifact::{
     apply_inventory_to_player, ArtifactInventory, OnHitEffectStack,
 };
+use crate::balance::{ArtifactsBalance, FireballParams, NovaParams, PlayerBalance};
 use crate::game_state::GameState;
 use crate::palette;
 use crate::run::RunScoped;
 use crate::stats::{ComputedStats, DirtyStats, Stat, StatCalculators};
 use crate::Faction;
 
-pub const FIREBALL_DAMAGE_PCT: f32 = 1.0;
-pub const FIREBALL_BASE_SPEED: f32 = 800.0;
-pub const FIREBALL_COOLDOWN: f32 = 0.5;
-pub const FIREBALL_SIZE: f32 = 60.0;
-pub const FIREBALL_GAP: f32 = 60.0;
-
 #[derive(Component)]
 pub struct Player;
 
@@ -48,6 +43,8 @@ pub fn spawn_player(
     mut commands: Commands,
     calculators: Res<StatCalculators>,
     inventory: Res<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
+    player_balance: Res<PlayerBalance>,
 ) {
     let entity = commands.spawn((
         Name::new("Player"),
@@ -62,14 +59,28 @@ pub fn spawn_player(
         PlayerInput,
         RunScoped,
         PlayerAbilityCooldowns::default(),
+        BeamCharge::default(),
     )).id();
 
-    let (modifiers, computed) =
-        apply_inventory_to_player(&mut commands, entity, &inventory, &calculators);
+    let (modifiers, computed) = apply_inventory_to_player(
+        &mut commands,
+        entity,
+        &inventory,
+        &artifacts,
+        &player_balance,
+        &calculators,
+    );
     let hp = computed.final_of(Stat::MaxLife);
+    let mana = computed.final_of(Stat::MaxMana);
     let mut dirty = DirtyStats::default();
     dirty.mark_all(Stat::iter());
-    commands.entity(entity).insert((modifiers, dirty, computed, Health { current: hp }));
+    commands.entity(entity).insert((
+        modifiers,
+        dirty,
+        computed,
+        Health { current: hp },
+        Mana { current: mana },
+    ));
 
     commands.entity(entity).with_children(|p| {
         p.spawn(Shadow);
@@ -114,10 +125,11 @@ pub fn fire_fireball(
     caster_faction: Faction,
     direction: Vec2,
     caster_stats: Option<&ComputedStats>,
+    params: &FireballParams,
 ) {
     let count = projectile_count(caster_stats, 1).max(1);
-    let speed = calc_projectile_speed(caster_stats, FIREBALL_BASE_SPEED);
-    let damage = calc_physical_damage(caster_stats, FIREBALL_DAMAGE_PCT);
+    let speed = calc_projectile_speed(caster_stats, params.speed);
+    let damage = calc_physical_damage(caster_stats, params.damage_pct);
     let pierce = caster_stats
         .map(|s| s.final_of(Stat::Pierce).max(0.0) as u32)
         .unwrap_or(0);
@@ -136,7 +148,7 @@ pub fn fire_fireball(
     let perpendicular = Vec2::new(-base_dir.y, base_dir.x);
 
     for i in 0..count {
-        let offset = FIREBALL_GAP * (i as f32 - (count as f32 - 1.0) / 2.0);
+        let offset = params.gap * (i as f32 - (count as f32 - 1.0) / 2.0);
         let spawn_pos_2d = caster_pos + perpendicular * offset;
         let ground = crate::coord::ground_pos(spawn_pos_2d);
         let velocity = base_dir * speed;
@@ -147,7 +159,7 @@ pub fn fire_fireball(
             caster_faction,
             Caster(caster),
             Projectile,
-            Size { value: FIREBALL_SIZE },
+            Size { value: params.size },
             Collider { shape: ColliderShape::Circle, sensor: true },
             RigidBody::Kinematic,
             LockedAxes::ROTATION_LOCKED.lock_translation_y(),
@@ -189,3 +201,27 @@ pub fn fire_fireball(
         });
     }
 }
+
+pub fn cast_nova(
+    commands: &mut Commands,
+    caster: Entity,
+    caster_pos: Vec2,
+    caster_stats: &ComputedStats,
+    params: &NovaParams,
+    targets: impl Iterator<Item = (Entity, Vec2)>,
+    pending: &mut MessageWriter<PendingDamage>,
+) {
+    let radius = params.radius * caster_stats.scale_of(Stat::AreaOfEffect);
+    let damage = calc_physical_damage(Some(caster_stats), params.damage_pct);
+    for (target, pos) in targets {
+        if pos.distance_squared(caster_pos) <= radius * radius {
+            pending.write(PendingDamage {
+                target,
+                amount: damage,
+                source: Some(caster),
+                on_hit: Default::default(),
+            });
+        }
+    }
+    crate::particles::start_particles(commands, "tower_explosion", caster_pos);
+}
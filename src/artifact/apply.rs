@@ -1,25 +1,19 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 use crate::actors::components::Health;
 use crate::actors::Player;
+use crate::balance::{ArtifactsBalance, PlayerBalance};
 use crate::stats::{
-    ComputedStats, DirtyStats, ModifierKind, Modifiers, Stat, StatCalculators,
+    ComputedStats, Condition, ConditionalModifiers, DirtyStats, ModifierKind, ModifierSource,
+    Modifiers, Stat, StatCalculators,
 };
 
 use super::effect::{ArtifactEffect, DefensiveKind, OnHitKind};
-use super::exotic::{attach_exotic, ExoticHelper};
+use super::exotic::{attach_exotic, detach_exotic, ExoticHelper};
 use super::inventory::ArtifactInventory;
 use super::kind::ArtifactKind;
 
-pub const PLAYER_BASE_STATS: &[(Stat, ModifierKind, f32)] = &[
-    (Stat::MaxLife, ModifierKind::Flat, 20.0),
-    (Stat::MovementSpeed, ModifierKind::Flat, 550.0),
-    (Stat::PhysicalDamage, ModifierKind::Flat, 1.0),
-    (Stat::CritChance, ModifierKind::Flat, 0.05),
-    (Stat::CritMultiplier, ModifierKind::Flat, 1.5),
-    (Stat::AttackSpeed, ModifierKind::Flat, 1.0),
-];
-
 #[derive(Message)]
 pub struct RebuildPlayerStateEvent;
 
@@ -28,113 +22,214 @@ pub fn register(app: &mut App) {
         .add_systems(Update, rebuild_player_state);
 }
 
-pub fn apply_artifact_to_modifiers(kind: ArtifactKind, m: &mut Modifiers) {
-    match kind.def().effect {
-        ArtifactEffect::StatMod { stat, kind: mk, value } => m.add(stat, mk, value),
+pub fn artifact_modifiers(effect: ArtifactEffect) -> Vec<(Stat, ModifierKind, f32)> {
+    match effect {
+        ArtifactEffect::StatMod { stat, kind: mk, value } => vec![(stat, mk, value)],
         ArtifactEffect::Multishot { extra } => {
-            m.add(Stat::ProjectileCount, ModifierKind::Flat, extra as f32)
+            vec![(Stat::ProjectileCount, ModifierKind::Flat, extra as f32)]
         }
         ArtifactEffect::Pierce { extra } => {
-            m.add(Stat::Pierce, ModifierKind::Flat, extra as f32)
+            vec![(Stat::Pierce, ModifierKind::Flat, extra as f32)]
         }
         ArtifactEffect::Ricochet { count } => {
-            m.add(Stat::Ricochet, ModifierKind::Flat, count as f32)
+            vec![(Stat::Ricochet, ModifierKind::Flat, count as f32)]
         }
         ArtifactEffect::Homing { strength } => {
-            m.add(Stat::HomingStrength, ModifierKind::Flat, strength)
+            vec![(Stat::HomingStrength, ModifierKind::Flat, strength)]
         }
         ArtifactEffect::Splash { radius, .. } => {
-            m.add(Stat::SplashRadius, ModifierKind::Flat, radius)
-        }
-        ArtifactEffect::OnHit(OnHitKind::Burn { dps, duration }) => {
-            m.add(Stat::BurnDPS, ModifierKind::Flat, dps);
-            m.add(Stat::BurnDuration, ModifierKind::Flat, duration);
+            vec![(Stat::SplashRadius, ModifierKind::Flat, radius)]
         }
+        ArtifactEffect::OnHit(OnHitKind::Burn { dps, duration }) => vec![
+            (Stat::BurnDPS, ModifierKind::Flat, dps),
+            (Stat::BurnDuration, ModifierKind::Flat, duration),
+        ],
         ArtifactEffect::OnHit(OnHitKind::Freeze {
             chance, duration, ..
-        }) => {
-            m.add(Stat::FreezeChance, ModifierKind::Flat, chance);
-            m.add(Stat::FreezeDuration, ModifierKind::Flat, duration);
-        }
+        }) => vec![
+            (Stat::FreezeChance, ModifierKind::Flat, chance),
+            (Stat::FreezeDuration, ModifierKind::Flat, duration),
+        ],
         ArtifactEffect::OnHit(OnHitKind::Lifesteal { pct }) => {
-            m.add(Stat::Lifesteal, ModifierKind::Flat, pct)
+            vec![(Stat::Lifesteal, ModifierKind::Flat, pct)]
         }
         ArtifactEffect::OnHit(OnHitKind::Knockback { force }) => {
-            m.add(Stat::KnockbackForce, ModifierKind::Flat, force)
+            vec![(Stat::KnockbackForce, ModifierKind::Flat, force)]
         }
         ArtifactEffect::OnHit(OnHitKind::Chain { count, .. }) => {
-            m.add(Stat::ChainCount, ModifierKind::Flat, count as f32)
+            vec![(Stat::ChainCount, ModifierKind::Flat, count as f32)]
         }
         ArtifactEffect::Defensive(DefensiveKind::Dodge { chance }) => {
-            m.add(Stat::DodgeChance, ModifierKind::Flat, chance)
+            vec![(Stat::DodgeChance, ModifierKind::Flat, chance)]
         }
         ArtifactEffect::Defensive(DefensiveKind::Thorns { reflect_pct }) => {
-            m.add(Stat::Thorns, ModifierKind::Flat, reflect_pct)
+            vec![(Stat::Thorns, ModifierKind::Flat, reflect_pct)]
         }
         ArtifactEffect::Defensive(DefensiveKind::Shield {
             max_block,
             recharge,
-        }) => {
-            m.add(Stat::ShieldMaxBlock, ModifierKind::Flat, max_block);
-            m.add(Stat::ShieldRecharge, ModifierKind::Flat, recharge);
-        }
-        ArtifactEffect::Exotic(_) => {}
+        }) => vec![
+            (Stat::ShieldMaxBlock, ModifierKind::Flat, max_block),
+            (Stat::ShieldRecharge, ModifierKind::Flat, recharge),
+        ],
+        ArtifactEffect::Exotic(_)
+        | ArtifactEffect::OnKill(_)
+        | ArtifactEffect::PrimaryAttack(_) => Vec::new(),
     }
 }
 
-pub fn build_player_modifiers(inv: &ArtifactInventory) -> Modifiers {
-    let mut mods = Modifiers::new();
-    for &(s, k, v) in PLAYER_BASE_STATS {
-        mods.add(s, k, v);
+#[derive(Component, Default)]
+pub struct AppliedArtifacts(HashMap<ArtifactKind, (ArtifactEffect, Option<Condition>)>);
+
+impl AppliedArtifacts {
+    pub fn effects(&self) -> impl Iterator<Item = (ArtifactKind, ArtifactEffect)> + '_ {
+        self.0.iter().map(|(&kind, &(effect, _))| (kind, effect))
     }
-    for kind in inv.active() {
-        apply_artifact_to_modifiers(kind, &mut mods);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sync_artifacts(
+    commands: &mut Commands,
+    player: Entity,
+    mods: &mut Modifiers,
+    conditional: &mut ConditionalModifiers,
+    applied: &mut AppliedArtifacts,
+    inv: &ArtifactInventory,
+    artifacts: &ArtifactsBalance,
+    helpers: &[(Entity, ArtifactKind)],
+) {
+    let wanted: HashMap<ArtifactKind, (ArtifactEffect, Option<Condition>)> = inv
+        .active()
+        .map(|k| {
+            let def = artifacts.get(k);
+            (k, (def.effect, def.condition))
+        })
+        .collect();
+
+    applied.0.retain(|&kind, &mut current| {
+        if wanted.get(&kind) == Some(&current) {
+            return true;
+        }
+        let source = ModifierSource::Artifact(kind);
+        mods.remove_source(source);
+        conditional.remove_owner(source, mods);
+        if let (ArtifactEffect::Exotic(e), _) = current {
+            let owned = helpers.iter().filter(|(_, k)| *k == kind).map(|(e, _)| *e);
+            detach_exotic(commands, player, e, owned);
+        }
+        false
+    });
+
+    for (kind, (effect, condition)) in wanted {
+        if applied.0.contains_key(&kind) {
+            continue;
+        }
+        let source = ModifierSource::Artifact(kind);
+        match condition {
+            Some(condition) => conditional.add(source, condition, artifact_modifiers(effect)),
+            None => mods.replace_source(source, artifact_modifiers(effect)),
+        }
+        if let ArtifactEffect::Exotic(e) = effect {
+            attach_exotic(commands, player, kind, e);
+        }
+        applied.0.insert(kind, (effect, condition));
     }
-    mods
+
+    let primary = inv
+        .active()
+        .filter_map(|k| match artifacts.get(k).effect {
+            ArtifactEffect::PrimaryAttack(attack) => Some(attack),
+            _ => None,
+        })
+        .last()
+        .unwrap_or_default();
+    commands.entity(player).insert(primary);
 }
 
 pub fn apply_inventory_to_player(
     commands: &mut Commands,
     player: Entity,
     inv: &ArtifactInventory,
+    artifacts: &ArtifactsBalance,
+    player_balance: &PlayerBalance,
     calculators: &StatCalculators,
 ) -> (Modifiers, ComputedStats) {
-    let mods = build_player_modifiers(inv);
+    let mut mods = Modifiers::new();
+    mods.replace_source(ModifierSource::Base, player_balance.base_stats.iter().copied());
+    let mut conditional = ConditionalModifiers::default();
+    let mut applied = AppliedArtifacts::default();
+    sync_artifacts(
+        commands,
+        player,
+        &mut mods,
+        &mut conditional,
+        &mut applied,
+        inv,
+        artifacts,
+        &[],
+    );
+    commands.entity(player).insert((conditional, applied));
+
     let mut dirty = DirtyStats::default();
     let mut computed = ComputedStats::default();
     dirty.mark_all(Stat::iter());
     calculators.recalculate(&mods, &mut computed, &mut dirty);
-
-    for kind in inv.active() {
-        if let ArtifactEffect::Exotic(e) = kind.def().effect {
-            attach_exotic(commands, player, e);
-        }
-    }
     (mods, computed)
 }
 
+type PlayerState = (
+    Entity,
+    &'static mut Modifiers,
+    &'static mut ConditionalModifiers,
+    &'static mut ComputedStats,
+    &'static mut DirtyStats,
+    &'static mut AppliedArtifacts,
+    Option<&'static mut Health>,
+);
+
+#[allow(clippy::too_many_arguments)]
 fn rebuild_player_state(
     mut commands: Commands,
     mut ev: MessageReader<RebuildPlayerStateEvent>,
     inventory: Res<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
+    player_balance: Res<PlayerBalance>,
     calculators: Res<StatCalculators>,
-    mut player_q: Query<(Entity, &ComputedStats, Option<&mut Health>), With<Player>>,
-    helper_q: Query<Entity, With<ExoticHelper>>,
+    mut player_q: Query<PlayerState, With<Player>>,
+    helper_q: Query<(Entity, &ExoticHelper)>,
 ) {
     if ev.read().last().is_none() {
         return;
     }
-    let Ok((player, old_computed, mut maybe_health)) = player_q.single_mut() else {
+    let Ok((
+        player,
+        mut mods,
+        mut conditional,
+        mut computed,
+        mut dirty,
+        mut applied,
+        mut maybe_health,
+    )) = player_q.single_mut()
+    else {
         return;
     };
-    let old_max_life = old_computed.final_of(Stat::MaxLife);
-    for e in &helper_q {
-        if let Ok(mut ec) = commands.get_entity(e) {
-            ec.despawn();
-        }
-    }
-    let (mods, computed) =
-        apply_inventory_to_player(&mut commands, player, &inventory, &calculators);
+    let old_max_life = computed.final_of(Stat::MaxLife);
+    let helpers: Vec<(Entity, ArtifactKind)> = helper_q.iter().map(|(e, h)| (e, h.0)).collect();
+
+    mods.replace_source(ModifierSource::Base, player_balance.base_stats.iter().copied());
+    sync_artifacts(
+        &mut commands,
+        player,
+        &mut mods,
+        &mut conditional,
+        &mut applied,
+        &inventory,
+        &artifacts,
+        &helpers,
+    );
+    dirty.mark_all(mods.take_changed());
+    calculators.recalculate(&mods, &mut computed, &mut dirty);
+
     let new_max_life = computed.final_of(Stat::MaxLife);
     let max_life_gain = (new_max_life - old_max_life).max(0.0);
     if max_life_gain > 0.0 {
@@ -142,25 +237,4 @@ fn rebuild_player_state(
             health.current = (health.current + max_life_gain).min(new_max_life);
         }
     }
-    let mut dirty = DirtyStats::default();
-    dirty.mark_all(Stat::iter());
-    commands
-        .entity(player)
-        .insert((mods, computed, dirty))
-        .remove::<crate::artifact::exotic::PeriodicAoe>();
-    for kind in inventory.active() {
-        if let ArtifactEffect::Exotic(crate::artifact::ExoticKind::PeriodicAoe {
-            interval,
-            radius,
-            damage_pct,
-        }) = kind.def().effect
-        {
-            commands.entity(player).insert(crate::artifact::exotic::PeriodicAoe {
-                interval,
-                radius,
-                damage_pct,
-                cooldown: interval,
-            });
-        }
-    }
 }
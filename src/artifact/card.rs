@@ -2,6 +2,7 @@ use bevy::prelude::*;
 
 use super::kind::ArtifactKind;
 use super::reroll::{RerollButton, RerollState};
+use crate::balance::ArtifactsBalance;
 use crate::palette;
 use crate::run::BreatherTimer;
 use crate::ui::widgets::{button_node, panel_node};
@@ -196,6 +197,7 @@ fn spawn_card(commands: &mut Commands, reroll: &RerollState) {
 }
 
 fn refresh_card_visuals(
+    artifacts: Res<ArtifactsBalance>,
     mut data_q: Query<(Entity, &mut ArtifactCardData)>,
     children_q: Query<&Children>,
     mut name_q: Query<&mut Text, (With<ArtifactCardName>, Without<ArtifactCardDesc>)>,
@@ -208,19 +210,21 @@ fn refresh_card_visuals(
             continue;
         }
         data.refresh = false;
-        let (name_str, desc_str, color) = match data.kind {
+        let (name_str, desc_str, color, border) = match data.kind {
             Some(k) => {
-                let d = k.def();
+                let d = artifacts.get(k);
                 (
-                    d.name.to_string(),
-                    d.description.to_string(),
+                    d.name.clone(),
+                    d.description.clone(),
                     palette::color("ui_artifact"),
+                    palette::color(d.tier.palette_key()),
                 )
             }
             None => (
                 "Pool exhausted".to_string(),
                 "No more artifacts to draw.".to_string(),
                 palette::color("ui_text_disabled"),
+                palette::color("ui_text_disabled"),
             ),
         };
 
@@ -236,7 +240,7 @@ fn refresh_card_visuals(
                 bg.0 = color;
             }
             if let Ok(mut bc) = border_q.get_mut(*d) {
-                *bc = BorderColor::all(color);
+                *bc = BorderColor::all(border);
             }
         }
     }
@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use super::effect::OnHitEffectStack;
+use crate::actors::components::combat::PendingDamage;
+use crate::palette;
+use crate::run::CombatScoped;
+use crate::schedule::GameSet;
+use crate::stats::{ComputedStats, Stat};
+use crate::wave::{CombatPhase, WaveEnemy};
+
+const CHAIN_RANGE: f32 = 250.0;
+const CHAIN_FALLOFF: f32 = 0.7;
+const ARC_DURATION: f32 = 0.2;
+const ARC_HEIGHT: f32 = 20.0;
+const ARC_SEGMENTS: usize = 5;
+const ARC_JITTER: f32 = 12.0;
+
+#[derive(Message)]
+pub struct ChainHit {
+    pub origin: Entity,
+    pub jumps: u32,
+    pub amount: f32,
+    pub source: Option<Entity>,
+    pub on_hit: OnHitEffectStack,
+}
+
+#[derive(Component)]
+struct LightningArc {
+    points: Vec<Vec3>,
+    remaining: f32,
+}
+
+pub fn register(app: &mut App) {
+    app.add_message::<ChainHit>().add_systems(
+        Update,
+        (spread_chain_hits, draw_lightning_arcs)
+            .in_set(GameSet::WaveManagement)
+            .run_if(in_state(CombatPhase::Running)),
+    );
+}
+
+/// Walks from `origin` to the nearest un-hit candidate within `range`, up to `jumps` times.
+pub fn chain_targets(
+    origin: (Entity, Vec2),
+    candidates: &[(Entity, Vec2)],
+    jumps: u32,
+    range: f32,
+) -> Vec<(Entity, Vec2)> {
+    let range_sq = range * range;
+    let mut current = origin.1;
+    let mut hit: Vec<(Entity, Vec2)> = Vec::new();
+    for _ in 0..jumps {
+        let next = candidates
+            .iter()
+            .filter(|(e, _)| *e != origin.0 && !hit.iter().any(|(h, _)| h == e))
+            .map(|&(e, p)| (e, p, p.distance_squared(current)))
+            .filter(|&(_, _, d)| d <= range_sq)
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+        let Some((e, p, _)) = next else { break };
+        hit.push((e, p));
+        current = p;
+    }
+    hit
+}
+
+fn spread_chain_hits(
+    mut commands: Commands,
+    mut chains: MessageReader<ChainHit>,
+    enemies: Query<(Entity, &Transform), With<WaveEnemy>>,
+    stats_q: Query<&ComputedStats>,
+    mut pending: MessageWriter<PendingDamage>,
+) {
+    let mut candidates: Option<Vec<(Entity, Vec2)>> = None;
+    for chain in chains.read() {
+        let Ok((_, origin_t)) = enemies.get(chain.origin) else { continue };
+        let candidates = candidates.get_or_insert_with(|| {
+            enemies
+                .iter()
+                .map(|(e, t)| (e, crate::coord::to_2d(t.translation)))
+                .collect()
+        });
+        let range = CHAIN_RANGE
+            * chain
+                .source
+                .and_then(|s| stats_q.get(s).ok())
+                .map_or(1.0, |s| s.scale_of(Stat::AreaOfEffect));
+        let origin = (chain.origin, crate::coord::to_2d(origin_t.translation));
+        // Chained hits carry every other on-hit effect but never chain again.
+        let on_hit = OnHitEffectStack { chain: None, ..chain.on_hit };
+
+        let mut path = vec![origin.1];
+        let mut amount = chain.amount;
+        for (target, pos) in chain_targets(origin, candidates, chain.jumps, range) {
+            amount *= CHAIN_FALLOFF;
+            pending.write(PendingDamage {
+                target,
+                amount,
+                source: chain.source,
+                on_hit,
+            });
+            path.push(pos);
+        }
+        if path.len() > 1 {
+            commands.spawn((
+                Name::new("LightningArc"),
+                LightningArc {
+                    points: jagged_path(&path),
+                    remaining: ARC_DURATION,
+                },
+                CombatScoped,
+            ));
+        }
+    }
+}
+
+fn jagged_path(path: &[Vec2]) -> Vec<Vec3> {
+    let mut points = vec![crate::coord::ground_pos(path[0]) + Vec3::Y * ARC_HEIGHT];
+    for pair in path.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let normal = (b - a).perp().normalize_or_zero();
+        for i in 1..=ARC_SEGMENTS {
+            let t = i as f32 / ARC_SEGMENTS as f32;
+            let jitter = if i == ARC_SEGMENTS {
+                0.0
+            } else {
+                (rand::random::<f32>() * 2.0 - 1.0) * ARC_JITTER
+            };
+            let p = a.lerp(b, t) + normal * jitter;
+            points.push(crate::coord::ground_pos(p) + Vec3::Y * ARC_HEIGHT);
+        }
+    }
+    points
+}
+
+fn draw_lightning_arcs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut arcs: Query<(Entity, &mut LightningArc)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut arc) in &mut arcs {
+        arc.remaining -= dt;
+        if arc.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let alpha = (arc.remaining / ARC_DURATION).clamp(0.0, 1.0);
+        gizmos.linestrip(
+            arc.points.iter().copied(),
+            palette::color_alpha("lightning_arc", alpha),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_of_enemies(n: u32, spacing: f32) -> Vec<(Entity, Vec2)> {
+        (0..n)
+            .map(|i| (Entity::from_bits(i as u64 + 1), Vec2::new(i as f32 * spacing, 0.0)))
+            .collect()
+    }
+
+    #[test]
+    fn chain_of_two_touches_three_enemies() {
+        let enemies = line_of_enemies(5, 100.0);
+        let hops = chain_targets(enemies[0], &enemies, 2, CHAIN_RANGE);
+        let touched: Vec<Entity> = std::iter::once(enemies[0].0)
+            .chain(hops.iter().map(|(e, _)| *e))
+            .collect();
+        assert_eq!(touched, vec![enemies[0].0, enemies[1].0, enemies[2].0]);
+    }
+
+    #[test]
+    fn chain_never_revisits_and_stops_out_of_range() {
+        let enemies = line_of_enemies(2, 100.0);
+        let hops = chain_targets(enemies[0], &enemies, 5, CHAIN_RANGE);
+        assert_eq!(hops.len(), 1);
+
+        let far = line_of_enemies(3, CHAIN_RANGE * 2.0);
+        assert!(chain_targets(far[0], &far, 2, CHAIN_RANGE).is_empty());
+    }
+}
@@ -4,16 +4,22 @@ use crate::artifact::apply::RebuildPlayerStateEvent;
 use crate::artifact::inventory::ArtifactInventory;
 use crate::artifact::pool;
 use crate::artifact::reroll::RerollState;
-use crate::run::BreatherTimer;
+use crate::balance::{ArtifactsBalance, Globals, WavesConfig};
+use crate::run::{BreatherTimer, RunState};
 
 pub fn register(app: &mut App) {
     app.add_systems(Update, on_breather_started);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn on_breather_started(
     breather: Option<Res<BreatherTimer>>,
     mut last_active: Local<bool>,
     mut inventory: ResMut<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
+    waves: Res<WavesConfig>,
+    globals: Res<Globals>,
+    run_state: Res<RunState>,
     mut reroll: ResMut<RerollState>,
     mut rebuild: MessageWriter<RebuildPlayerStateEvent>,
 ) {
@@ -21,9 +27,10 @@ fn on_breather_started(
     if active && !*last_active {
         let mut rng = rand::rng();
         let prev_accepted = inventory.collected.last().copied();
-        let drawn = pool::roll_artifact(&inventory, prev_accepted, &mut rng);
+        let wave = waves.for_wave(run_state.wave, &globals.endless);
+        let drawn = pool::roll_artifact(&inventory, &artifacts, &wave, prev_accepted, &mut rng);
         if let Some(k) = drawn {
-            inventory.add(k);
+            inventory.add(k, &artifacts);
             rebuild.write(RebuildPlayerStateEvent);
         }
         *reroll = RerollState {
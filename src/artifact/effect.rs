@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::stats::{ComputedStats, ModifierKind, Stat};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ArtifactEffect {
     StatMod {
         stat: Stat,
@@ -27,9 +27,11 @@ pub enum ArtifactEffect {
     OnHit(OnHitKind),
     Defensive(DefensiveKind),
     Exotic(ExoticKind),
+    OnKill(OnKillKind),
+    PrimaryAttack(PrimaryAttack),
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum OnHitKind {
     Burn { dps: f32, duration: f32 },
     Freeze { chance: f32, duration: f32 },
@@ -38,14 +40,45 @@ pub enum OnHitKind {
     Chain { count: u32 },
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Component, Copy, Clone, Debug, PartialEq, Default)]
+pub enum PrimaryAttack {
+    #[default]
+    Fireball,
+    Beam {
+        damage_pct: f32,
+        charge: f32,
+        length: f32,
+        width: f32,
+    },
+    Knife {
+        damage_pct: f32,
+        range: f32,
+        speed: f32,
+        cooldown: f32,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OnKillKind {
+    Explosion { damage_pct: f32, radius: f32 },
+    Heal { amount: f32, chance: f32 },
+    Buff {
+        stat: Stat,
+        kind: ModifierKind,
+        value: f32,
+        duration: f32,
+        max_stacks: u32,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum DefensiveKind {
     Shield { max_block: f32, recharge: f32 },
     Dodge { chance: f32 },
     Thorns { reflect_pct: f32 },
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum ExoticKind {
     Turret { fire_interval: f32, damage_pct: f32 },
     OrbitingOrbs { count: u32, radius: f32, damage: f32 },
@@ -2,20 +2,25 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 
 use super::effect::ExoticKind;
+use super::kind::ArtifactKind;
 use crate::actors::components::combat::{
     Caster, OnCollisionDamage, PendingDamage,
 };
 use crate::actors::components::physics::{Collider, ColliderShape, Size};
 use crate::actors::components::visual::{Shadow, Shape, ShapeColor, ShapeKind};
 use crate::actors::Player;
+use crate::balance::PlayerBalance;
 use crate::palette;
 use crate::run::CombatScoped;
 use crate::schedule::GameSet;
+use crate::stats::{ComputedStats, Stat};
 use crate::wave::{CombatPhase, WaveEnemy};
 use crate::Faction;
 
 #[derive(Component)]
-pub struct ExoticHelper;
+pub struct ExoticHelper(pub ArtifactKind);
+
+const ORB_SIZE: f32 = 32.0;
 
 #[derive(Component)]
 pub struct OrbitOrb {
@@ -54,7 +59,12 @@ fn ability_color() -> ShapeColor {
     ShapeColor { r, g, b, a: 1.0, flash }
 }
 
-pub fn attach_exotic(commands: &mut Commands, player: Entity, kind: ExoticKind) {
+pub fn attach_exotic(
+    commands: &mut Commands,
+    player: Entity,
+    artifact: ArtifactKind,
+    kind: ExoticKind,
+) {
     match kind {
         ExoticKind::OrbitingOrbs {
             count,
@@ -66,12 +76,12 @@ pub fn attach_exotic(commands: &mut Commands, player: Entity, kind: ExoticKind)
                 let orb = commands
                     .spawn((
                         Name::new("OrbitOrb"),
-                        ExoticHelper,
+                        ExoticHelper(artifact),
                         Faction::Player,
                         Caster(player),
                         Transform::from_translation(Vec3::ZERO),
                         Visibility::default(),
-                        Size { value: 32.0 },
+                        Size { value: ORB_SIZE },
                         Collider {
                             shape: ColliderShape::Circle,
                             sensor: true,
@@ -107,7 +117,7 @@ pub fn attach_exotic(commands: &mut Commands, player: Entity, kind: ExoticKind)
             let turret = commands
                 .spawn((
                     Name::new("ExoticTurret"),
-                    ExoticHelper,
+                    ExoticHelper(artifact),
                     Faction::Player,
                     Transform::from_translation(Vec3::new(150.0, 0.0, 0.0)),
                     Visibility::default(),
@@ -132,24 +142,56 @@ pub fn attach_exotic(commands: &mut Commands, player: Entity, kind: ExoticKind)
                 });
             });
         }
-        ExoticKind::PeriodicAoe { .. } => {
-            // PeriodicAoe is attached as component on player by apply.rs, not here.
+        ExoticKind::PeriodicAoe {
+            interval,
+            radius,
+            damage_pct,
+        } => {
+            commands.entity(player).insert(PeriodicAoe {
+                interval,
+                radius,
+                damage_pct,
+                cooldown: interval,
+            });
         }
     }
 }
 
+pub fn detach_exotic(
+    commands: &mut Commands,
+    player: Entity,
+    kind: ExoticKind,
+    helpers: impl IntoIterator<Item = Entity>,
+) {
+    for e in helpers {
+        if let Ok(mut ec) = commands.get_entity(e) {
+            ec.despawn();
+        }
+    }
+    if let ExoticKind::PeriodicAoe { .. } = kind {
+        commands.entity(player).remove::<PeriodicAoe>();
+    }
+}
+
 fn update_orbiting_orbs(
     time: Res<Time>,
-    player_q: Query<&Transform, With<Player>>,
-    mut orbs: Query<(&OrbitOrb, &mut Transform), Without<Player>>,
+    player_q: Query<(&Transform, &ComputedStats), With<Player>>,
+    mut orbs: Query<(&OrbitOrb, &mut Transform, &mut Size), Without<Player>>,
 ) {
-    let Ok(player_transform) = player_q.single() else { return };
+    let Ok((player_transform, stats)) = player_q.single() else { return };
     let player_pos = crate::coord::to_2d(player_transform.translation);
     let now = time.elapsed_secs();
-    for (orb, mut transform) in &mut orbs {
-        let angle = orb.angle_offset + now * orb.speed;
-        let pos_2d = player_pos + Vec2::new(angle.cos(), angle.sin()) * orb.radius;
+    let area = stats.scale_of(Stat::AreaOfEffect);
+    let size = ORB_SIZE * area;
+    // Orb speed is angular, so flat projectile speed (px/s) doesn't apply.
+    let spin = stats.multiplier_of(Stat::ProjectileSpeed);
+    for (orb, mut transform, mut orb_size) in &mut orbs {
+        let angle = orb.angle_offset + now * orb.speed * spin;
+        let pos_2d = player_pos + Vec2::new(angle.cos(), angle.sin()) * orb.radius * area;
         transform.translation = crate::coord::ground_pos(pos_2d) + Vec3::Y * 0.5;
+        if orb_size.value != size {
+            orb_size.value = size;
+        }
     }
 }
 
@@ -158,7 +200,8 @@ fn tick_turrets(
     time: Res<Time>,
     mut turrets: Query<(Entity, &Transform, &mut Turret, &Faction)>,
     enemies: Query<&Transform, (With<WaveEnemy>, Without<Turret>)>,
-    player_stats: Query<&crate::stats::ComputedStats, With<Player>>,
+    player_stats: Query<&ComputedStats, With<Player>>,
+    player_balance: Res<PlayerBalance>,
 ) {
     let dt = time.delta_secs();
     for (turret_entity, transform, mut turret, faction) in &mut turrets {
@@ -192,44 +235,46 @@ fn tick_turrets(
             *faction,
             dir,
             scaled_stats.as_ref(),
+            &player_balance.fireball,
         );
         turret.cooldown = turret.fire_interval;
     }
 }
 
 fn scale_stats_for_turret(
-    base: &crate::stats::ComputedStats,
+    base: &ComputedStats,
     damage_pct: f32,
-) -> crate::stats::ComputedStats {
+) -> ComputedStats {
     let mut copy = base.clone();
-    let dmg = copy.final_of(crate::stats::Stat::PhysicalDamage) * damage_pct;
-    copy.set_final(crate::stats::Stat::PhysicalDamage, dmg);
+    let dmg = copy.final_of(Stat::PhysicalDamage) * damage_pct;
+    copy.set_final(Stat::PhysicalDamage, dmg);
     copy
 }
 
 fn tick_periodic_aoe(
     mut commands: Commands,
     time: Res<Time>,
-    mut player_q: Query<(&Transform, &mut PeriodicAoe, &crate::stats::ComputedStats), With<Player>>,
+    mut player_q: Query<(Entity, &Transform, &mut PeriodicAoe, &ComputedStats), With<Player>>,
     enemies: Query<(Entity, &Transform), With<WaveEnemy>>,
     mut pending: MessageWriter<PendingDamage>,
 ) {
-    let Ok((transform, mut aoe, stats)) = player_q.single_mut() else { return };
+    let Ok((player, transform, mut aoe, stats)) = player_q.single_mut() else { return };
     let dt = time.delta_secs();
     if aoe.cooldown > 0.0 {
         aoe.cooldown -= dt;
         return;
     }
     let pos = crate::coord::to_2d(transform.translation);
-    let radius_sq = aoe.radius * aoe.radius;
-    let damage = stats.final_of(crate::stats::Stat::PhysicalDamage) * aoe.damage_pct;
+    let radius = aoe.radius * stats.scale_of(Stat::AreaOfEffect);
+    let radius_sq = radius * radius;
+    let damage = stats.final_of(Stat::PhysicalDamage) * aoe.damage_pct;
     for (e, et) in &enemies {
         let ep = crate::coord::to_2d(et.translation);
         if (ep - pos).length_squared() <= radius_sq {
             pending.write(PendingDamage {
                 target: e,
                 amount: damage,
-                source: None,
+                source: Some(player),
                 on_hit: Default::default(),
             });
         }
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use std::collections::HashSet;
 
+use crate::balance::ArtifactsBalance;
 use crate::game_state::GameState;
 
 use super::kind::ArtifactKind;
@@ -19,20 +20,20 @@ impl ArtifactInventory {
             .filter(|k| !self.disabled.contains(k))
     }
 
-    pub fn add(&mut self, k: ArtifactKind) {
-        for r in k.def().replaces {
+    pub fn add(&mut self, k: ArtifactKind, artifacts: &ArtifactsBalance) {
+        for r in &artifacts.get(k).replaces {
             self.disabled.insert(*r);
         }
         self.collected.push(k);
     }
 
-    pub fn pop_last(&mut self) -> Option<ArtifactKind> {
+    pub fn pop_last(&mut self, artifacts: &ArtifactsBalance) -> Option<ArtifactKind> {
         let popped = self.collected.pop()?;
-        for r in popped.def().replaces {
+        for r in &artifacts.get(popped).replaces {
             let still_replaced = self
                 .collected
                 .iter()
-                .any(|k| k.def().replaces.contains(r));
+                .any(|k| artifacts.get(*k).replaces.contains(r));
             if !still_replaced {
                 self.disabled.remove(r);
             }
@@ -2,21 +2,27 @@ use bevy::prelude::*;
 
 mod apply;
 mod card;
+mod chain;
 mod drop;
 mod effect;
 mod exotic;
 mod inventory;
 mod kind;
+mod on_kill;
 mod pool;
 mod reroll;
 mod status;
 mod wave_end;
 
 pub use apply::{apply_inventory_to_player, RebuildPlayerStateEvent};
-pub use effect::{ExoticKind, OnHitEffectStack};
+pub use chain::ChainHit;
+pub use effect::{
+    ArtifactEffect, DefensiveKind, ExoticKind, OnHitEffectStack, OnHitKind, OnKillKind,
+    PrimaryAttack,
+};
 pub use inventory::ArtifactInventory;
-pub use kind::ArtifactKind;
-pub use status::{Burning, Frozen};
+pub use kind::{ArtifactDef, ArtifactKind, ArtifactTier};
+pub use status::{frozen, Burning};
 
 pub struct ArtifactPlugin;
 
@@ -30,5 +36,7 @@ impl Plugin for ArtifactPlugin {
         wave_end::register(app);
         status::register(app);
         exotic::register(app);
+        chain::register(app);
+        on_kill::register(app);
     }
 }
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use super::apply::AppliedArtifacts;
+use super::effect::{ArtifactEffect, OnKillKind};
+use super::exotic::ExoticHelper;
+use crate::actors::components::combat::{PendingDamage, PendingHeal};
+use crate::actors::components::{death_system, DeathEvent};
+use crate::actors::Player;
+use crate::schedule::PostGameSet;
+use crate::stats::{ApplyTimedModifier, ComputedStats, Stacking, Stat, TimedModifier};
+use crate::wave::WaveEnemy;
+use crate::GameState;
+
+pub fn register(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        trigger_on_kill_effects
+            .in_set(PostGameSet)
+            .after(death_system)
+            .run_if(in_state(GameState::Playing)),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn trigger_on_kill_effects(
+    mut commands: Commands,
+    mut deaths: MessageReader<DeathEvent>,
+    player_q: Query<(Entity, &AppliedArtifacts, &ComputedStats), With<Player>>,
+    helpers: Query<(), With<ExoticHelper>>,
+    enemies: Query<(Entity, &Transform), With<WaveEnemy>>,
+    mut pending: MessageWriter<PendingDamage>,
+    mut timed: MessageWriter<ApplyTimedModifier>,
+    mut heals: MessageWriter<PendingHeal>,
+) {
+    let Ok((player, applied, stats)) = player_q.single() else {
+        deaths.clear();
+        return;
+    };
+    let effects: Vec<_> = applied
+        .effects()
+        .filter_map(|(kind, effect)| match effect {
+            ArtifactEffect::OnKill(k) => Some((kind, k)),
+            _ => None,
+        })
+        .collect();
+    if effects.is_empty() {
+        deaths.clear();
+        return;
+    }
+
+    for death in deaths.read() {
+        if !credited_to_player(death.killer, player, |e| helpers.contains(e)) {
+            continue;
+        }
+        let Ok((_, victim_t)) = enemies.get(death.entity) else { continue };
+        let pos = crate::coord::to_2d(victim_t.translation);
+
+        for &(kind, effect) in &effects {
+            match effect {
+                OnKillKind::Explosion { damage_pct, radius } => {
+                    let radius = radius * stats.scale_of(Stat::AreaOfEffect);
+                    let radius_sq = radius * radius;
+                    let damage = stats.final_of(Stat::PhysicalDamage) * damage_pct;
+                    for (e, et) in &enemies {
+                        if e == death.entity {
+                            continue;
+                        }
+                        let ep = crate::coord::to_2d(et.translation);
+                        if (ep - pos).length_squared() <= radius_sq {
+                            pending.write(PendingDamage {
+                                target: e,
+                                amount: damage,
+                                source: Some(player),
+                                on_hit: Default::default(),
+                            });
+                        }
+                    }
+                    crate::particles::start_particles(&mut commands, "tower_explosion", pos);
+                }
+                OnKillKind::Heal { amount, chance } => {
+                    if chance < 1.0 && rand::random::<f32>() >= chance {
+                        continue;
+                    }
+                    heals.write(PendingHeal { target: player, amount });
+                }
+                OnKillKind::Buff {
+                    stat,
+                    kind: modifier_kind,
+                    value,
+                    duration,
+                    max_stacks,
+                } => {
+                    timed.write(ApplyTimedModifier {
+                        target: player,
+                        modifier: TimedModifier {
+                            id: kind.id(),
+                            entries: vec![(stat, modifier_kind, value)],
+                            duration: duration * stats.scale_of(Stat::Duration),
+                            stacking: Stacking::Stack { max: max_stacks },
+                        },
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// On-kill effects fire for kills by the player or by one of their exotic helpers.
+fn credited_to_player(
+    killer: Option<Entity>,
+    player: Entity,
+    is_helper: impl Fn(Entity) -> bool,
+) -> bool {
+    killer.is_some_and(|k| k == player || is_helper(k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_player_and_helper_kills_count() {
+        let [player, helper, enemy] = [1, 2, 3].map(Entity::from_bits);
+        let is_helper = |e: Entity| e == helper;
+        assert!(credited_to_player(Some(player), player, is_helper));
+        assert!(credited_to_player(Some(helper), player, is_helper));
+        assert!(!credited_to_player(Some(enemy), player, is_helper));
+        assert!(!credited_to_player(None, player, is_helper));
+    }
+}
@@ -1,46 +1,151 @@
+use rand::seq::IndexedRandom;
 use rand::Rng;
 
+use crate::balance::{ArtifactsBalance, WaveDef};
+
 use super::effect::ArtifactEffect;
 use super::inventory::ArtifactInventory;
-use super::kind::ArtifactKind;
+use super::kind::{ArtifactKind, ArtifactTier};
 
-pub fn is_stat_mod(k: ArtifactKind) -> bool {
-    matches!(k.def().effect, ArtifactEffect::StatMod { .. })
+pub fn is_stat_mod(k: ArtifactKind, artifacts: &ArtifactsBalance) -> bool {
+    matches!(artifacts.get(k).effect, ArtifactEffect::StatMod { .. })
 }
 
 pub fn roll_artifact(
     inv: &ArtifactInventory,
+    artifacts: &ArtifactsBalance,
+    wave: &WaveDef,
     prev_accepted: Option<ArtifactKind>,
     rng: &mut impl Rng,
 ) -> Option<ArtifactKind> {
-    pick(inv, prev_accepted, None, rng)
+    pick(inv, artifacts, wave, prev_accepted, None, rng)
 }
 
 pub fn roll_artifact_excluding(
     inv: &ArtifactInventory,
+    artifacts: &ArtifactsBalance,
+    wave: &WaveDef,
     prev_accepted: Option<ArtifactKind>,
     skip: ArtifactKind,
     rng: &mut impl Rng,
 ) -> Option<ArtifactKind> {
-    pick(inv, prev_accepted, Some(skip), rng)
+    pick(inv, artifacts, wave, prev_accepted, Some(skip), rng)
 }
 
+/// Rolls a tier by the wave's weights among tiers that still have candidates, then a kind
+/// uniformly within it. Falls back to any candidate when every remaining tier weighs zero.
 fn pick(
     inv: &ArtifactInventory,
+    artifacts: &ArtifactsBalance,
+    wave: &WaveDef,
     prev_accepted: Option<ArtifactKind>,
     skip: Option<ArtifactKind>,
     rng: &mut impl Rng,
 ) -> Option<ArtifactKind> {
-    let block_stat_mod = prev_accepted.map(is_stat_mod).unwrap_or(false);
+    let block_stat_mod = prev_accepted
+        .map(|k| is_stat_mod(k, artifacts))
+        .unwrap_or(false);
     let candidates: Vec<ArtifactKind> = ArtifactKind::ALL
         .iter()
         .copied()
         .filter(|&k| !inv.contains(k))
         .filter(|&k| Some(k) != skip)
-        .filter(|&k| !(block_stat_mod && is_stat_mod(k)))
+        .filter(|&k| !(block_stat_mod && is_stat_mod(k, artifacts)))
+        .collect();
+    let tiers: Vec<ArtifactTier> = ArtifactTier::ALL
+        .iter()
+        .copied()
+        .filter(|&t| candidates.iter().any(|&k| artifacts.get(k).tier == t))
+        .collect();
+    let Ok(&tier) = tiers.choose_weighted(rng, |t| wave.tier_weight(*t)) else {
+        return candidates.choose(rng).copied();
+    };
+    let in_tier: Vec<ArtifactKind> = candidates
+        .into_iter()
+        .filter(|&k| artifacts.get(k).tier == tier)
         .collect();
-    if candidates.is_empty() {
-        return None;
+    in_tier.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::artifact::ArtifactDef;
+
+    fn balance() -> ArtifactsBalance {
+        let defs = ArtifactKind::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| {
+                let def = ArtifactDef {
+                    name: kind.id().to_string(),
+                    description: String::new(),
+                    tier: ArtifactTier::ALL[i % ArtifactTier::ALL.len()],
+                    effect: ArtifactEffect::Multishot { extra: 1 },
+                    condition: None,
+                    replaces: Vec::new(),
+                };
+                (kind, def)
+            })
+            .collect();
+        ArtifactsBalance { defs }
+    }
+
+    fn wave(weights: [f32; 4]) -> WaveDef {
+        WaveDef {
+            enemy_variety: 1,
+            spawn_interval: 1.0,
+            hp_multiplier: 1.0,
+            damage_multiplier: 1.0,
+            duration: 20.0,
+            breather: 5.0,
+            weights: HashMap::new(),
+            tier_weights: ArtifactTier::ALL.iter().copied().zip(weights).collect(),
+        }
+    }
+
+    #[test]
+    fn rolls_follow_tier_weights() {
+        let artifacts = balance();
+        let wave = wave([40.0, 30.0, 20.0, 10.0]);
+        let mut rng = StdRng::seed_from_u64(7);
+        let rolls = 10_000;
+        let inv = ArtifactInventory::default();
+        let mut counts: HashMap<ArtifactTier, u32> = HashMap::new();
+        for _ in 0..rolls {
+            let kind = roll_artifact(&inv, &artifacts, &wave, None, &mut rng).unwrap();
+            *counts.entry(artifacts.get(kind).tier).or_default() += 1;
+        }
+        for (tier, expected) in ArtifactTier::ALL.iter().zip([0.4, 0.3, 0.2, 0.1]) {
+            let share = counts.get(tier).copied().unwrap_or(0) as f32 / rolls as f32;
+            assert!((share - expected).abs() < 0.02, "{tier:?}: {share}");
+        }
+    }
+
+    #[test]
+    fn zero_weight_tiers_wait_until_nothing_else_is_left() {
+        let artifacts = balance();
+        let wave = wave([1.0, 0.0, 0.0, 0.0]);
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut inv = ArtifactInventory::default();
+        for _ in 0..100 {
+            let kind = roll_artifact(&inv, &artifacts, &wave, None, &mut rng).unwrap();
+            assert_eq!(artifacts.get(kind).tier, ArtifactTier::Common);
+        }
+
+        for &kind in ArtifactKind::ALL {
+            if artifacts.get(kind).tier == ArtifactTier::Common {
+                inv.add(kind, &artifacts);
+            }
+        }
+        let kind = roll_artifact(&inv, &artifacts, &wave, None, &mut rng).unwrap();
+        assert_ne!(artifacts.get(kind).tier, ArtifactTier::Common);
+        inv.collected = ArtifactKind::ALL.to_vec();
+        assert!(roll_artifact(&inv, &artifacts, &wave, None, &mut rng).is_none());
     }
-    Some(candidates[rng.random_range(0..candidates.len())])
 }
@@ -5,7 +5,8 @@ use super::card::ArtifactCardData;
 use super::inventory::ArtifactInventory;
 use super::kind::ArtifactKind;
 use super::pool;
-use crate::run::BreatherTimer;
+use crate::balance::{ArtifactsBalance, Globals, WavesConfig};
+use crate::run::{BreatherTimer, RunState};
 use crate::ui::widgets::ReleasedButtons;
 
 #[derive(Component)]
@@ -24,10 +25,15 @@ pub fn register(app: &mut App) {
     );
 }
 
+#[allow(clippy::too_many_arguments)]
 fn reroll_button_system(
     buttons: ReleasedButtons<RerollButton>,
     mut state: ResMut<RerollState>,
     mut inventory: ResMut<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
+    waves: Res<WavesConfig>,
+    globals: Res<Globals>,
+    run_state: Res<RunState>,
     mut rebuild: MessageWriter<RebuildPlayerStateEvent>,
     mut card_q: Query<&mut ArtifactCardData>,
 ) {
@@ -36,13 +42,21 @@ fn reroll_button_system(
             return;
         }
         let Some(prev) = state.current else { return };
-        inventory.pop_last();
+        inventory.pop_last(&artifacts);
 
         let prev_accepted = inventory.collected.last().copied();
         let mut rng = rand::rng();
-        let new = pool::roll_artifact_excluding(&inventory, prev_accepted, prev, &mut rng);
+        let wave = waves.for_wave(run_state.wave, &globals.endless);
+        let new = pool::roll_artifact_excluding(
+            &inventory,
+            &artifacts,
+            &wave,
+            prev_accepted,
+            prev,
+            &mut rng,
+        );
         if let Some(k) = new {
-            inventory.add(k);
+            inventory.add(k, &artifacts);
             rebuild.write(RebuildPlayerStateEvent);
             state.current = Some(k);
         } else {
@@ -1,8 +1,8 @@
-use avian3d::prelude::*;
 use bevy::prelude::*;
 
 use crate::actors::components::combat::PendingDamage;
 use crate::schedule::GameSet;
+use crate::stats::{ModifierKind, Stacking, Stat, TimedModifier};
 use crate::wave::CombatPhase;
 
 #[derive(Component)]
@@ -12,28 +12,26 @@ pub struct Burning {
     pub source: Option<Entity>,
 }
 
-#[derive(Component)]
-pub struct Frozen {
-    pub remaining: f32,
-    pub slow_pct: f32,
+const FREEZE_SLOW: f32 = 0.6;
+
+pub fn frozen(duration: f32) -> TimedModifier {
+    TimedModifier {
+        id: "frozen",
+        entries: vec![(Stat::MovementSpeed, ModifierKind::More, -FREEZE_SLOW)],
+        duration,
+        stacking: Stacking::Refresh,
+    }
 }
 
 pub fn register(app: &mut App) {
     app.add_systems(
         Update,
-        (tick_burn, tick_freeze, frozen_movement_modifier)
+        tick_burn
             .in_set(GameSet::WaveManagement)
             .run_if(in_state(CombatPhase::Running)),
     );
 }
 
-fn frozen_movement_modifier(mut q: Query<(&Frozen, &mut LinearVelocity)>) {
-    for (frozen, mut velocity) in &mut q {
-        let scale = (1.0 - frozen.slow_pct).max(0.0);
-        velocity.0 *= scale;
-    }
-}
-
 fn tick_burn(
     mut commands: Commands,
     time: Res<Time>,
@@ -59,19 +57,3 @@ fn tick_burn(
         }
     }
 }
-
-fn tick_freeze(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut q: Query<(Entity, &mut Frozen)>,
-) {
-    let dt = time.delta_secs();
-    for (entity, mut frozen) in &mut q {
-        frozen.remaining -= dt;
-        if frozen.remaining <= 0.0 {
-            if let Ok(mut ec) = commands.get_entity(entity) {
-                ec.remove::<Frozen>();
-            }
-        }
-    }
-}
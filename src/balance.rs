@@ -1,10 +1,18 @@
 use bevy::prelude::*;
 
+pub mod check;
+pub mod convert;
+pub mod error;
 pub mod loader;
 pub mod parser;
+pub mod text;
 pub mod types;
 
-pub use types::{Globals, MobCommonStats, MobsBalance, WavesConfig};
+pub use types::{
+    ArtifactsBalance, EndlessScaling, FireballParams, GhostBehaviour, Globals, JumperBehaviour, MobBalance,
+    MobCommonStats, MobsBalance, NovaParams, PlayerBalance, SlimeBehaviour, SpinnerBehaviour, StatsBalance,
+    TowerBehaviour, WaveDef, WavesConfig,
+};
 
 pub struct BalancePlugin;
 
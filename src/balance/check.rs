@@ -0,0 +1,84 @@
+use crate::actors::MobKind;
+
+use super::error::BalanceIssue;
+use super::types::Balance;
+
+pub fn cross_check(balance: &Balance) -> Vec<BalanceIssue> {
+    let waves = &balance.waves;
+    let endless = &balance.globals.endless;
+    let mut issues = Vec::new();
+
+    for kind in MobKind::iter() {
+        if waves.unlock_wave(kind) == 0 {
+            issues.push(BalanceIssue::sheet(
+                "Waves",
+                format!("mob {} is never unlocked", kind.id()),
+            ));
+        }
+    }
+
+    for wave in 1..=waves.waves.len() as u32 {
+        let unlocked: Vec<MobKind> = MobKind::iter()
+            .filter(|k| {
+                let u = waves.unlock_wave(*k);
+                u > 0 && u <= wave
+            })
+            .collect();
+        if unlocked.is_empty() {
+            issues.push(BalanceIssue::sheet(
+                "Waves",
+                format!("wave {wave} has no unlocked mobs"),
+            ));
+        } else if unlocked.iter().all(|k| waves.for_wave(wave, endless).weight(*k) <= 0.0) {
+            issues.push(BalanceIssue::sheet(
+                "Waves",
+                format!("wave {wave}: every unlocked mob has weight 0"),
+            ));
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::error::ParseMode;
+    use crate::balance::loader::load_xlsx;
+
+    fn balance() -> Balance {
+        load_xlsx("assets/balance.xlsx", ParseMode::CollectAll).expect("parse ok")
+    }
+
+    fn causes(balance: &Balance) -> Vec<String> {
+        cross_check(balance).into_iter().map(|i| i.cause).collect()
+    }
+
+    #[test]
+    fn shipped_balance_passes() {
+        assert!(causes(&balance()).is_empty());
+    }
+
+    #[test]
+    fn reports_a_mob_that_is_never_unlocked() {
+        let mut balance = balance();
+        balance.waves.mob_unlocks.remove(&MobKind::Tower);
+        assert_eq!(causes(&balance), vec!["mob tower is never unlocked"]);
+    }
+
+    #[test]
+    fn reports_a_wave_with_no_unlocked_mobs() {
+        let mut balance = balance();
+        for unlock in balance.waves.mob_unlocks.values_mut() {
+            *unlock = (*unlock).max(2);
+        }
+        assert_eq!(causes(&balance), vec!["wave 1 has no unlocked mobs"]);
+    }
+
+    #[test]
+    fn reports_a_wave_whose_unlocked_mobs_all_weigh_zero() {
+        let mut balance = balance();
+        balance.waves.waves[0].weights = MobKind::iter().map(|k| (k, 0.0)).collect();
+        assert_eq!(causes(&balance), vec!["wave 1: every unlocked mob has weight 0"]);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use calamine::{Data, Range, Reader, Xlsx};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::error::{BalanceError, ParseMode};
+use super::loader::load_from_sheets;
+use super::text::{read_sheet_file, sheet_path, write_sheet, EXTENSION};
+use super::types::Balance;
+
+const SHEET_ORDER: &[&str] = &["Mobs", "Waves", "Globals", "Artifacts", "Player", "Stats"];
+
+pub type Sheets = Vec<(String, Range<Data>)>;
+
+pub fn read_xlsx_sheets(path: &Path) -> Result<Sheets, String> {
+    let mut wb: Xlsx<_> =
+        calamine::open_workbook(path).map_err(|e| format!("opening {}: {e}", path.display()))?;
+    wb.sheet_names()
+        .into_iter()
+        .map(|name| {
+            let range = wb.worksheet_range(&name).map_err(|e| format!("{name}: {e}"))?;
+            Ok((name, range))
+        })
+        .collect()
+}
+
+pub fn read_text_sheets(dir: &Path) -> Result<Sheets, String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("reading {}: {e}", dir.display()))?;
+    let mut names: Vec<String> = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort_by_key(|n| {
+        let known = SHEET_ORDER.iter().position(|k| k == n).unwrap_or(SHEET_ORDER.len());
+        (known, n.clone())
+    });
+    names
+        .into_iter()
+        .map(|name| {
+            let range = read_sheet_file(&sheet_path(dir, &name))?;
+            Ok((name, range))
+        })
+        .collect()
+}
+
+pub fn validate(sheets: &Sheets, mode: ParseMode) -> Result<Balance, BalanceError> {
+    load_from_sheets(
+        |name| {
+            sheets
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, range)| range.clone())
+                .ok_or_else(|| "sheet not found".to_string())
+        },
+        mode,
+    )
+}
+
+pub fn write_text_sheets(dir: &Path, sheets: &Sheets) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("creating {}: {e}", dir.display()))?;
+    for (name, range) in sheets {
+        let path = sheet_path(dir, name);
+        std::fs::write(&path, write_sheet(range))
+            .map_err(|e| format!("writing {}: {e}", path.display()))?;
+    }
+    Ok(())
+}
+
+pub fn write_xlsx(path: &Path, sheets: &Sheets) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("creating {}: {e}", path.display()))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut put = |name: &str, body: String| -> Result<(), String> {
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(body.as_bytes()).map_err(|e| e.to_string())
+    };
+
+    let mut overrides = String::new();
+    let mut workbook_sheets = String::new();
+    let mut rels = String::new();
+    for (i, (name, range)) in sheets.iter().enumerate() {
+        let n = i + 1;
+        overrides.push_str(&format!(
+            r#"<Override PartName="/xl/worksheets/sheet{n}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+        ));
+        workbook_sheets.push_str(&format!(
+            r#"<sheet name="{}" sheetId="{n}" r:id="rId{n}"/>"#,
+            xml_escape(name)
+        ));
+        rels.push_str(&format!(
+            r#"<Relationship Id="rId{n}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+        ));
+        put(&format!("xl/worksheets/sheet{n}.xml"), sheet_xml(range))?;
+    }
+
+    put(
+        "[Content_Types].xml",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{overrides}</Types>"#
+        ),
+    )?;
+    put(
+        "_rels/.rels",
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+    )?;
+    put(
+        "xl/workbook.xml",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{workbook_sheets}</sheets></workbook>"#
+        ),
+    )?;
+    put(
+        "xl/_rels/workbook.xml.rels",
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{rels}</Relationships>"#
+        ),
+    )?;
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn sheet_xml(range: &Range<Data>) -> String {
+    let mut rows = String::new();
+    for (r, row) in range.rows().enumerate() {
+        rows.push_str(&format!(r#"<row r="{}">"#, r + 1));
+        for (c, cell) in row.iter().enumerate() {
+            let at = format!("{}{}", column_name(c), r + 1);
+            match cell {
+                Data::Empty => {}
+                Data::Int(i) => rows.push_str(&format!(r#"<c r="{at}"><v>{i}</v></c>"#)),
+                Data::Float(f) => rows.push_str(&format!(r#"<c r="{at}"><v>{f}</v></c>"#)),
+                Data::Bool(b) => {
+                    rows.push_str(&format!(r#"<c r="{at}" t="b"><v>{}</v></c>"#, u8::from(*b)))
+                }
+                other => rows.push_str(&format!(
+                    r#"<c r="{at}" t="inlineStr"><is><t>{}</t></is></c>"#,
+                    xml_escape(&other.to_string())
+                )),
+            }
+        }
+        rows.push_str("</row>");
+    }
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{rows}</sheetData></worksheet>"#
+    )
+}
+
+fn column_name(mut idx: usize) -> String {
+    let mut name = Vec::new();
+    idx += 1;
+    while idx > 0 {
+        let rem = (idx - 1) % 26;
+        name.push(b'A' + rem as u8);
+        idx = (idx - 1) / 26;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn used(row: &[Data]) -> &[Data] {
+        let end = row.iter().rposition(|c| !matches!(c, Data::Empty)).map_or(0, |i| i + 1);
+        &row[..end]
+    }
+
+    fn assert_same(a: &Sheets, b: &Sheets) {
+        let names = |s: &Sheets| s.iter().map(|(n, _)| n.clone()).collect::<Vec<_>>();
+        let mut expected = names(a);
+        expected.sort();
+        let mut got = names(b);
+        got.sort();
+        assert_eq!(expected, got);
+        for (name, range) in a {
+            let (_, other) = b.iter().find(|(n, _)| n == name).unwrap();
+            let rows: Vec<_> = range.rows().map(used).filter(|r| !r.is_empty()).collect();
+            let other_rows: Vec<_> = other.rows().map(used).filter(|r| !r.is_empty()).collect();
+            assert_eq!(rows, other_rows, "sheet {name}");
+        }
+    }
+
+    #[test]
+    fn xlsx_round_trips_through_text() {
+        let dir = std::env::temp_dir().join(format!("balance-convert-{}", std::process::id()));
+        let original = read_xlsx_sheets(Path::new("assets/balance.xlsx")).expect("read xlsx");
+        assert!(validate(&original, ParseMode::CollectAll).is_ok());
+
+        write_text_sheets(&dir, &original).expect("write text");
+        let text = read_text_sheets(&dir).expect("read text");
+        assert_same(&original, &text);
+        assert!(validate(&text, ParseMode::CollectAll).is_ok());
+
+        let xlsx = dir.join("balance.xlsx");
+        write_xlsx(&xlsx, &text).expect("write xlsx");
+        let back = read_xlsx_sheets(&xlsx).expect("read written xlsx");
+        assert_same(&original, &back);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
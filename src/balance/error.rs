@@ -0,0 +1,143 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMode {
+    FailFast,
+    CollectAll,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceIssue {
+    pub sheet: Option<String>,
+    pub row: Option<usize>,
+    pub column: Option<String>,
+    pub cause: String,
+}
+
+impl BalanceIssue {
+    pub fn workbook(cause: impl Into<String>) -> Self {
+        Self {
+            sheet: None,
+            row: None,
+            column: None,
+            cause: cause.into(),
+        }
+    }
+
+    pub fn sheet(sheet: &str, cause: impl Into<String>) -> Self {
+        Self {
+            sheet: Some(sheet.to_string()),
+            row: None,
+            column: None,
+            cause: cause.into(),
+        }
+    }
+}
+
+impl fmt::Display for BalanceIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.sheet {
+            Some(sheet) => write!(f, "{sheet}")?,
+            None => write!(f, "workbook")?,
+        }
+        if let Some(row) = self.row {
+            write!(f, " row {row}")?;
+        }
+        if let Some(column) = &self.column {
+            write!(f, " [{column}]")?;
+        }
+        write!(f, ": {}", self.cause)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BalanceError {
+    pub issues: Vec<BalanceIssue>,
+}
+
+impl From<BalanceIssue> for BalanceError {
+    fn from(issue: BalanceIssue) -> Self {
+        Self {
+            issues: vec![issue],
+        }
+    }
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.issues.as_slice() {
+            [] => write!(f, "unknown balance error"),
+            [one] => write!(f, "{one}"),
+            many => {
+                write!(f, "{} problems:", many.len())?;
+                for issue in many {
+                    write!(f, "\n  - {issue}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {}
+
+pub(super) struct Issues {
+    mode: ParseMode,
+    list: Vec<BalanceIssue>,
+}
+
+impl Issues {
+    pub fn new(mode: ParseMode) -> Self {
+        Self {
+            mode,
+            list: Vec::new(),
+        }
+    }
+
+    pub fn sheet<'a>(&'a mut self, name: &'static str) -> SheetIssues<'a> {
+        SheetIssues { name, issues: self }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn into_error(self) -> BalanceError {
+        BalanceError { issues: self.list }
+    }
+
+    fn push(&mut self, issue: BalanceIssue) -> Result<(), BalanceError> {
+        self.list.push(issue);
+        match self.mode {
+            ParseMode::FailFast => Err(BalanceError {
+                issues: std::mem::take(&mut self.list),
+            }),
+            ParseMode::CollectAll => Ok(()),
+        }
+    }
+}
+
+pub(super) struct SheetIssues<'a> {
+    name: &'static str,
+    issues: &'a mut Issues,
+}
+
+impl SheetIssues<'_> {
+    pub fn sheet(&mut self, cause: impl Into<String>) -> Result<(), BalanceError> {
+        self.issues.push(BalanceIssue::sheet(self.name, cause))
+    }
+
+    pub fn cell(
+        &mut self,
+        row: usize,
+        column: &str,
+        cause: impl Into<String>,
+    ) -> Result<(), BalanceError> {
+        self.issues.push(BalanceIssue {
+            sheet: Some(self.name.to_string()),
+            row: Some(row),
+            column: Some(column.to_string()),
+            cause: cause.into(),
+        })
+    }
+}
@@ -1,9 +1,15 @@
 use std::io::{Read, Seek};
+use std::path::Path;
 
 use bevy::prelude::*;
-use calamine::{Reader, Xlsx};
+use calamine::{Data, Range, Reader, Xlsx};
 
-use super::parser::{parse_balance, BalanceError};
+#[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
+use crate::artifact::RebuildPlayerStateEvent;
+
+use super::error::{BalanceError, BalanceIssue, ParseMode};
+use super::parser::parse_balance;
+use super::text::load_text_dir;
 use super::types::Balance;
 
 #[cfg(any(not(feature = "dev"), target_arch = "wasm32"))]
@@ -12,33 +18,86 @@ const BALANCE_XLSX: &[u8] = include_bytes!("../../assets/balance.xlsx");
 #[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
 const XLSX_PATH: &str = "assets/balance.xlsx";
 
+#[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
+const TEXT_DIR: &str = "assets/balance";
+
+const PARSE_MODE: ParseMode = if cfg!(feature = "dev") {
+    ParseMode::CollectAll
+} else {
+    ParseMode::FailFast
+};
+
 pub fn load_balance() -> Result<Balance, BalanceError> {
     #[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
     {
-        use calamine::open_workbook;
-        let mut wb: Xlsx<_> =
-            open_workbook(XLSX_PATH).map_err(|e| format!("opening {XLSX_PATH}: {e}"))?;
-        load_from_workbook(&mut wb)
+        if cfg!(feature = "text-balance") {
+            info!("Loading balance from {TEXT_DIR}/");
+            load_text_dir(TEXT_DIR, PARSE_MODE)
+        } else {
+            if Path::new(TEXT_DIR).is_dir() {
+                warn!("{TEXT_DIR}/ is ignored; build with --features text-balance to load it");
+            }
+            info!("Loading balance from {XLSX_PATH}");
+            load_xlsx(XLSX_PATH, PARSE_MODE)
+        }
     }
     #[cfg(any(not(feature = "dev"), target_arch = "wasm32"))]
     {
+        info!("Loading embedded balance.xlsx");
         let cursor = std::io::Cursor::new(BALANCE_XLSX);
-        let mut wb = Xlsx::new(cursor).map_err(|e| format!("reading embedded xlsx: {e}"))?;
-        load_from_workbook(&mut wb)
+        let mut wb = Xlsx::new(cursor)
+            .map_err(|e| BalanceIssue::workbook(format!("reading embedded xlsx: {e}")))?;
+        load_from_workbook(&mut wb, PARSE_MODE)
     }
 }
 
-fn load_from_workbook<R: Read + Seek>(wb: &mut Xlsx<R>) -> Result<Balance, BalanceError> {
-    let mobs = wb
-        .worksheet_range("Mobs")
-        .map_err(|e| format!("sheet Mobs: {e}"))?;
-    let waves = wb
-        .worksheet_range("Waves")
-        .map_err(|e| format!("sheet Waves: {e}"))?;
-    let globals = wb
-        .worksheet_range("Globals")
-        .map_err(|e| format!("sheet Globals: {e}"))?;
-    parse_balance(&mobs, &waves, &globals)
+pub fn load_path(path: impl AsRef<Path>, mode: ParseMode) -> Result<Balance, BalanceError> {
+    let path = path.as_ref();
+    if path.is_dir() {
+        load_text_dir(path, mode)
+    } else {
+        load_xlsx(path, mode)
+    }
+}
+
+pub fn load_xlsx(path: impl AsRef<Path>, mode: ParseMode) -> Result<Balance, BalanceError> {
+    let path = path.as_ref();
+    let mut wb: Xlsx<_> = calamine::open_workbook(path)
+        .map_err(|e| BalanceIssue::workbook(format!("opening {}: {e}", path.display())))?;
+    load_from_workbook(&mut wb, mode)
+}
+
+fn load_from_workbook<R: Read + Seek>(
+    wb: &mut Xlsx<R>,
+    mode: ParseMode,
+) -> Result<Balance, BalanceError> {
+    load_from_sheets(|name| wb.worksheet_range(name).map_err(|e| e.to_string()), mode)
+}
+
+pub fn load_from_sheets(
+    mut sheet: impl FnMut(&str) -> Result<Range<Data>, String>,
+    mode: ParseMode,
+) -> Result<Balance, BalanceError> {
+    let mut missing = BalanceError::default();
+    let mut sheet = |name: &str| match sheet(name) {
+        Ok(range) => Some(range),
+        Err(e) => {
+            missing.issues.push(BalanceIssue::sheet(name, e));
+            None
+        }
+    };
+    let mobs = sheet("Mobs");
+    let waves = sheet("Waves");
+    let globals = sheet("Globals");
+    let artifacts = sheet("Artifacts");
+    let player = sheet("Player");
+    let stats = sheet("Stats");
+    let (Some(mobs), Some(waves), Some(globals), Some(artifacts), Some(player), Some(stats)) =
+        (mobs, waves, globals, artifacts, player, stats)
+    else {
+        return Err(missing);
+    };
+    parse_balance(&mobs, &waves, &globals, &artifacts, &player, &stats, mode)
 }
 
 pub fn setup_balance(mut commands: Commands) {
@@ -57,6 +116,9 @@ fn install_balance(commands: &mut Commands, balance: Balance) {
     commands.insert_resource(balance.mobs.clone());
     commands.insert_resource(balance.waves.clone());
     commands.insert_resource(balance.globals.clone());
+    commands.insert_resource(balance.artifacts.clone());
+    commands.insert_resource(balance.player.clone());
+    commands.insert_resource(balance.stats.clone());
     commands.insert_resource(balance);
 }
 
@@ -68,6 +130,7 @@ pub fn reload_balance(input: Res<ButtonInput<KeyCode>>, mut commands: Commands)
     match load_balance() {
         Ok(balance) => {
             install_balance(&mut commands, balance);
+            commands.write_message(RebuildPlayerStateEvent);
             info!("Balance reloaded");
         }
         Err(e) => error!("Balance reload failed: {e}"),
@@ -3,170 +3,418 @@ use std::collections::HashMap;
 use calamine::{Data, Range};
 
 use crate::actors::MobKind;
+use crate::artifact::{
+    ArtifactDef, ArtifactEffect, ArtifactKind, ArtifactTier, DefensiveKind, ExoticKind, OnHitKind,
+    OnKillKind, PrimaryAttack,
+};
+use crate::stats::{Condition, Formula, ModifierKind, Stat, StatCalculators};
 
+use super::error::{BalanceError, Issues, ParseMode, SheetIssues};
 use super::types::{
-    Balance, Globals, MobCommonStats, MobsBalance, WaveDef, WavesConfig,
+    ArtifactsBalance, Balance, EndlessScaling, FireballParams, GhostBehaviour, Globals,
+    JumperBehaviour, MobBalance, MobCommonStats, MobsBalance, NovaParams, PlayerBalance,
+    SlimeBehaviour, SpinnerBehaviour, StatsBalance, TowerBehaviour, WaveDef, WavesConfig,
 };
 
-pub type BalanceError = String;
+type Headers = HashMap<String, usize>;
+type MobParams = HashMap<&'static str, f32>;
+type WeightColumns = Vec<(MobKind, usize, String)>;
+
+struct CellError {
+    column: &'static str,
+    cause: String,
+}
+
+impl CellError {
+    fn new(column: &'static str, cause: impl Into<String>) -> Self {
+        Self { column, cause: cause.into() }
+    }
+}
 
 pub fn parse_balance(
     mobs: &Range<Data>,
     waves: &Range<Data>,
     globals: &Range<Data>,
+    artifacts: &Range<Data>,
+    player: &Range<Data>,
+    stats: &Range<Data>,
+    mode: ParseMode,
 ) -> Result<Balance, BalanceError> {
-    let mobs = parse_mobs(mobs).map_err(|e| format!("sheet Mobs: {e}"))?;
-    let waves = parse_waves(waves).map_err(|e| format!("sheet Waves: {e}"))?;
-    let globals = parse_globals(globals).map_err(|e| format!("sheet Globals: {e}"))?;
-    Ok(Balance { mobs, waves, globals })
+    let mut issues = Issues::new(mode);
+    let mobs = parse_mobs(mobs, &mut issues.sheet("Mobs"))?;
+    let waves = parse_waves(waves, &mut issues.sheet("Waves"))?;
+    let globals = parse_globals(globals, &mut issues.sheet("Globals"))?;
+    let artifacts = parse_artifacts(artifacts, &mut issues.sheet("Artifacts"))?;
+    let player = parse_player(player, &mut issues.sheet("Player"))?;
+    let stats = parse_stats(stats, &mut issues.sheet("Stats"))?;
+    match (mobs, waves, globals, artifacts, player, stats) {
+        (Some(mobs), Some(waves), Some(globals), Some(artifacts), Some(player), Some(stats))
+            if issues.is_empty() =>
+        {
+            Ok(Balance { mobs, waves, globals, artifacts, player, stats })
+        }
+        _ => Err(issues.into_error()),
+    }
 }
 
-pub fn parse_mobs(range: &Range<Data>) -> Result<MobsBalance, BalanceError> {
-    let headers = parse_headers(range)?;
-    let c_id = required_col(&headers, "id")?;
-    let c_hp = required_col(&headers, "hp")?;
-    let c_damage = required_col(&headers, "damage")?;
+fn parse_mobs(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<MobsBalance>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_id = required_col(&headers, "id", issues)?;
+    let c_hp = required_col(&headers, "hp", issues)?;
+    let c_damage = required_col(&headers, "damage", issues)?;
     let c_speed = headers.get("speed").copied();
-    let c_size = required_col(&headers, "size")?;
+    let c_size = required_col(&headers, "size", issues)?;
     let c_mass = headers.get("mass").copied();
     let c_attack_speed = headers.get("attack_speed").copied();
+    let (Some(c_id), Some(c_hp), Some(c_damage), Some(c_size)) = (c_id, c_hp, c_damage, c_size)
+    else {
+        return Ok(None);
+    };
 
-    let mut map: HashMap<MobKind, MobCommonStats> = HashMap::new();
+    let mut complete = true;
+    let mut map: HashMap<MobKind, (MobCommonStats, MobParams)> = HashMap::new();
     for (row_idx, row) in data_rows(range) {
-        let id = cell_str(row.get(c_id))
-            .ok_or_else(|| format!("row {row_idx}: empty id"))?;
-        let kind = parse_mob_id(&id)
-            .map_err(|e| format!("row {row_idx}: {e}"))?;
-        if map.contains_key(&kind) {
-            return Err(format!("row {row_idx}: duplicate mob id {id}"));
-        }
-
-        let hp = cell_f32(row.get(c_hp))
-            .map_err(|e| format!("row {row_idx} hp: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: hp required"))?;
-        let damage = cell_f32(row.get(c_damage))
-            .map_err(|e| format!("row {row_idx} damage: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: damage required"))?;
-        let size = cell_f32(row.get(c_size))
-            .map_err(|e| format!("row {row_idx} size: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: size required"))?;
-        let speed = match c_speed {
-            Some(c) => cell_f32(row.get(c)).map_err(|e| format!("row {row_idx} speed: {e}"))?,
-            None => None,
-        };
-        let mass = match c_mass {
-            Some(c) => cell_f32(row.get(c)).map_err(|e| format!("row {row_idx} mass: {e}"))?,
-            None => None,
-        };
-        let attack_speed = match c_attack_speed {
-            Some(c) => cell_f32(row.get(c))
-                .map_err(|e| format!("row {row_idx} attack_speed: {e}"))?,
-            None => None,
-        };
+        let kind = required_cell(issues, row_idx, row, c_id, "id", |c| {
+            cell_str(c).map(|s| parse_mob_id(&s)).transpose()
+        })?;
+        if let Some(kind) = kind {
+            if map.contains_key(&kind) {
+                issues.cell(row_idx, "id", format!("duplicate mob id {}", kind.id()))?;
+                continue;
+            }
+        }
 
-        map.insert(kind, MobCommonStats { hp, damage, speed, size, mass, attack_speed });
+        let hp = required_cell(issues, row_idx, row, c_hp, "hp", cell_f32)?;
+        let damage = required_cell(issues, row_idx, row, c_damage, "damage", cell_f32)?;
+        let size = required_cell(issues, row_idx, row, c_size, "size", cell_f32)?;
+        let speed = optional_cell(issues, row_idx, row, c_speed, "speed", cell_f32)?;
+        let mass = optional_cell(issues, row_idx, row, c_mass, "mass", cell_f32)?;
+        let attack_speed =
+            optional_cell(issues, row_idx, row, c_attack_speed, "attack_speed", cell_f32)?;
+
+        let mut params = MobParams::new();
+        for name in MobKind::iter().flat_map(mob_behaviour_columns) {
+            let parse = if *name == "projectile_count" { cell_count } else { cell_f32 };
+            let col = headers.get(*name).copied();
+            let Some(value) = optional_cell(issues, row_idx, row, col, name, parse)? else {
+                continue;
+            };
+            if let Some(kind) = kind.filter(|k| !mob_behaviour_columns(*k).contains(name)) {
+                issues.cell(row_idx, name, format!("does not apply to {}", kind.id()))?;
+                continue;
+            }
+            if value < 0.0 {
+                issues.cell(row_idx, name, "must be >= 0")?;
+                continue;
+            }
+            params.insert(*name, value);
+        }
+        if let Some((name, cause)) = kind.and_then(|k| behaviour_issue(k, &params)) {
+            issues.cell(row_idx, name, cause)?;
+        }
+
+        let (Some(kind), Some(hp), Some(damage), Some(size)) = (kind, hp, damage, size) else {
+            complete = false;
+            continue;
+        };
+        let stats = MobCommonStats { hp, damage, speed, size, mass, attack_speed };
+        map.insert(kind, (stats, params));
+    }
+    if !complete {
+        return Ok(None);
     }
 
     for kind in MobKind::iter() {
         if !map.contains_key(&kind) {
-            return Err(format!("mob {} missing", kind.id()));
+            issues.sheet(format!("mob {} missing", kind.id()))?;
+            complete = false;
         }
     }
+    if !complete {
+        return Ok(None);
+    }
 
-    Ok(MobsBalance {
-        ghost: map.remove(&MobKind::Ghost).unwrap(),
-        tower: map.remove(&MobKind::Tower).unwrap(),
-        slime_small: map.remove(&MobKind::SlimeSmall).unwrap(),
-        jumper: map.remove(&MobKind::Jumper).unwrap(),
-        spinner: map.remove(&MobKind::Spinner).unwrap(),
-    })
+    Ok(Some(MobsBalance {
+        ghost: mob_balance(&mut map, MobKind::Ghost, ghost_behaviour),
+        tower: mob_balance(&mut map, MobKind::Tower, tower_behaviour),
+        slime_small: mob_balance(&mut map, MobKind::SlimeSmall, slime_behaviour),
+        jumper: mob_balance(&mut map, MobKind::Jumper, jumper_behaviour),
+        spinner: mob_balance(&mut map, MobKind::Spinner, spinner_behaviour),
+    }))
 }
 
-pub fn parse_waves(range: &Range<Data>) -> Result<WavesConfig, BalanceError> {
-    let headers = parse_headers(range)?;
-    let c_wave = required_col(&headers, "wave")?;
-    let c_unlocks = required_col(&headers, "unlocks")?;
-    let c_variety = required_col(&headers, "enemy_variety")?;
-    let c_interval = required_col(&headers, "spawn_interval")?;
-    let c_hp = required_col(&headers, "hp_multiplier")?;
-    let c_dmg = required_col(&headers, "damage_multiplier")?;
+fn mob_behaviour_columns(kind: MobKind) -> &'static [&'static str] {
+    match kind {
+        MobKind::Ghost => &["visible_distance", "invisible_distance"],
+        MobKind::Tower => &["explosion_radius", "spread"],
+        MobKind::SlimeSmall => &["lunge_duration"],
+        MobKind::Jumper => &["projectile_count", "projectile_speed", "spread_degrees"],
+        MobKind::Spinner => &[
+            "idle_weight",
+            "windup_weight",
+            "charge_weight",
+            "cooldown_weight",
+            "contact_radius",
+        ],
+    }
+}
 
-    let mut rows: Vec<(u32, WaveDef, Option<MobKind>)> = Vec::new();
-    for (row_idx, row) in data_rows(range) {
-        let wave = cell_u32(row.get(c_wave))
-            .map_err(|e| format!("row {row_idx} wave: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: wave required"))?;
-
-        let unlocks = match cell_str(row.get(c_unlocks)) {
-            Some(s) => Some(
-                parse_mob_id(&s)
-                    .map_err(|e| format!("row {row_idx} unlocks: {e}"))?,
-            ),
-            None => None,
-        };
+/// Cross-column checks on the values the behaviour will run with, defaults included.
+fn behaviour_issue(kind: MobKind, p: &MobParams) -> Option<(&'static str, &'static str)> {
+    match kind {
+        MobKind::Ghost => {
+            let b = ghost_behaviour(p);
+            (b.invisible_distance <= b.visible_distance)
+                .then_some(("invisible_distance", "must be > visible_distance"))
+        }
+        MobKind::Spinner => {
+            let b = spinner_behaviour(p);
+            let total = b.idle_weight + b.windup_weight + b.charge_weight + b.cooldown_weight;
+            (total <= 0.0).then_some(("idle_weight", "phase weights must sum to > 0"))
+        }
+        _ => None,
+    }
+}
+
+fn mob_balance<B>(
+    map: &mut HashMap<MobKind, (MobCommonStats, MobParams)>,
+    kind: MobKind,
+    behaviour: fn(&MobParams) -> B,
+) -> MobBalance<B> {
+    let (stats, params) = map.remove(&kind).unwrap();
+    MobBalance { stats, behaviour: behaviour(&params) }
+}
 
-        let variety = cell_u32(row.get(c_variety))
-            .map_err(|e| format!("row {row_idx} enemy_variety: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: enemy_variety required"))?;
-        let interval = cell_f32(row.get(c_interval))
-            .map_err(|e| format!("row {row_idx} spawn_interval: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: spawn_interval required"))?;
-        let hp_m = cell_f32(row.get(c_hp))
-            .map_err(|e| format!("row {row_idx} hp_multiplier: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: hp_multiplier required"))?;
-        let dmg_m = cell_f32(row.get(c_dmg))
-            .map_err(|e| format!("row {row_idx} damage_multiplier: {e}"))?
-            .ok_or_else(|| format!("row {row_idx}: damage_multiplier required"))?;
+fn param(p: &MobParams, name: &str, default: f32) -> f32 {
+    p.get(name).copied().unwrap_or(default)
+}
+
+fn ghost_behaviour(p: &MobParams) -> GhostBehaviour {
+    let d = GhostBehaviour::default();
+    GhostBehaviour {
+        visible_distance: param(p, "visible_distance", d.visible_distance),
+        invisible_distance: param(p, "invisible_distance", d.invisible_distance),
+    }
+}
+
+fn tower_behaviour(p: &MobParams) -> TowerBehaviour {
+    let d = TowerBehaviour::default();
+    TowerBehaviour {
+        explosion_radius: param(p, "explosion_radius", d.explosion_radius),
+        spread: param(p, "spread", d.spread),
+    }
+}
+
+fn slime_behaviour(p: &MobParams) -> SlimeBehaviour {
+    let d = SlimeBehaviour::default();
+    SlimeBehaviour { lunge_duration: param(p, "lunge_duration", d.lunge_duration) }
+}
+
+fn jumper_behaviour(p: &MobParams) -> JumperBehaviour {
+    let d = JumperBehaviour::default();
+    JumperBehaviour {
+        projectile_count: p
+            .get("projectile_count")
+            .map(|v| *v as u32)
+            .unwrap_or(d.projectile_count),
+        projectile_speed: param(p, "projectile_speed", d.projectile_speed),
+        spread_degrees: param(p, "spread_degrees", d.spread_degrees),
+    }
+}
 
-        if variety == 0 {
-            return Err(format!("wave {wave}: enemy_variety must be > 0"));
+fn spinner_behaviour(p: &MobParams) -> SpinnerBehaviour {
+    let d = SpinnerBehaviour::default();
+    SpinnerBehaviour {
+        idle_weight: param(p, "idle_weight", d.idle_weight),
+        windup_weight: param(p, "windup_weight", d.windup_weight),
+        charge_weight: param(p, "charge_weight", d.charge_weight),
+        cooldown_weight: param(p, "cooldown_weight", d.cooldown_weight),
+        contact_radius: param(p, "contact_radius", d.contact_radius),
+    }
+}
+
+fn parse_waves(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<WavesConfig>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_wave = required_col(&headers, "wave", issues)?;
+    let c_unlocks = required_col(&headers, "unlocks", issues)?;
+    let c_variety = required_col(&headers, "enemy_variety", issues)?;
+    let c_interval = required_col(&headers, "spawn_interval", issues)?;
+    let c_hp = required_col(&headers, "hp_multiplier", issues)?;
+    let c_dmg = required_col(&headers, "damage_multiplier", issues)?;
+    let c_duration = required_col(&headers, "duration", issues)?;
+    let c_breather = required_col(&headers, "breather", issues)?;
+    let (
+        Some(c_wave),
+        Some(c_unlocks),
+        Some(c_variety),
+        Some(c_interval),
+        Some(c_hp),
+        Some(c_dmg),
+        Some(c_duration),
+        Some(c_breather),
+    ) = (c_wave, c_unlocks, c_variety, c_interval, c_hp, c_dmg, c_duration, c_breather)
+    else {
+        return Ok(None);
+    };
+    let Some(weight_cols) = weight_columns(&headers, issues)? else { return Ok(None) };
+    let mut tier_cols = Vec::new();
+    for &tier in ArtifactTier::ALL {
+        let name = format!("tier_{}", tier.id());
+        if let Some(col) = required_col(&headers, &name, issues)? {
+            tier_cols.push((tier, col, name));
+        }
+    }
+    if tier_cols.len() < ArtifactTier::ALL.len() {
+        return Ok(None);
+    }
+
+    let mut complete = true;
+    let mut numbers: Option<Vec<(usize, u32)>> = Some(Vec::new());
+    let mut rows: Vec<(usize, u32, WaveDef, Option<MobKind>)> = Vec::new();
+    for (row_idx, row) in data_rows(range) {
+        let wave = required_cell(issues, row_idx, row, c_wave, "wave", cell_u32)?;
+        match (wave, numbers.as_mut()) {
+            (Some(w), Some(numbers)) => numbers.push((row_idx, w)),
+            (None, _) => numbers = None,
+            _ => {}
+        }
+        let unlocks = optional_cell(issues, row_idx, row, Some(c_unlocks), "unlocks", |c| {
+            cell_str(c).map(|s| parse_mob_id(&s)).transpose()
+        })?;
+        let variety = required_cell(issues, row_idx, row, c_variety, "enemy_variety", cell_u32)?;
+        let interval =
+            required_cell(issues, row_idx, row, c_interval, "spawn_interval", cell_f32)?;
+        let hp_m = required_cell(issues, row_idx, row, c_hp, "hp_multiplier", cell_f32)?;
+        let dmg_m = required_cell(issues, row_idx, row, c_dmg, "damage_multiplier", cell_f32)?;
+        let duration = required_cell(issues, row_idx, row, c_duration, "duration", cell_f32)?;
+        let breather = required_cell(issues, row_idx, row, c_breather, "breather", cell_f32)?;
+
+        if variety == Some(0) {
+            issues.cell(row_idx, "enemy_variety", "must be > 0")?;
+        }
+        if interval.is_some_and(|v| v <= 0.0) {
+            issues.cell(row_idx, "spawn_interval", "must be > 0")?;
         }
-        if interval <= 0.0 {
-            return Err(format!("wave {wave}: spawn_interval must be > 0"));
+        if hp_m.is_some_and(|v| v <= 0.0) {
+            issues.cell(row_idx, "hp_multiplier", "must be > 0")?;
         }
-        if hp_m <= 0.0 {
-            return Err(format!("wave {wave}: hp_multiplier must be > 0"));
+        if dmg_m.is_some_and(|v| v <= 0.0) {
+            issues.cell(row_idx, "damage_multiplier", "must be > 0")?;
         }
-        if dmg_m <= 0.0 {
-            return Err(format!("wave {wave}: damage_multiplier must be > 0"));
+        if duration.is_some_and(|v| v <= 0.0) {
+            issues.cell(row_idx, "duration", "must be > 0")?;
+        }
+        if breather.is_some_and(|v| v < 0.0) {
+            issues.cell(row_idx, "breather", "must be >= 0")?;
+        }
+        let mut weights = HashMap::new();
+        for (kind, col, name) in &weight_cols {
+            match cell_f32(row.get(*col)) {
+                Ok(Some(v)) if v < 0.0 => {
+                    issues.cell(row_idx, name, "must be >= 0")?;
+                    complete = false;
+                }
+                Ok(Some(v)) => {
+                    weights.insert(*kind, v);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    issues.cell(row_idx, name, e)?;
+                    complete = false;
+                }
+            }
+        }
+        let mut tier_weights = HashMap::new();
+        for (tier, col, name) in &tier_cols {
+            match required_cell(issues, row_idx, row, *col, name, cell_f32)? {
+                Some(v) if v < 0.0 => {
+                    issues.cell(row_idx, name, "must be >= 0")?;
+                    complete = false;
+                }
+                Some(v) => {
+                    tier_weights.insert(*tier, v);
+                }
+                None => complete = false,
+            }
         }
 
+        let (
+            Some(wave),
+            Some(variety),
+            Some(interval),
+            Some(hp_m),
+            Some(dmg_m),
+            Some(duration),
+            Some(breather),
+        ) = (wave, variety, interval, hp_m, dmg_m, duration, breather)
+        else {
+            complete = false;
+            continue;
+        };
         rows.push((
+            row_idx,
             wave,
             WaveDef {
                 enemy_variety: variety,
                 spawn_interval: interval,
                 hp_multiplier: hp_m,
                 damage_multiplier: dmg_m,
+                duration,
+                breather,
+                weights,
+                tier_weights,
             },
             unlocks,
         ));
     }
 
-    if rows.is_empty() {
-        return Err("no waves".into());
-    }
-    rows.sort_by_key(|(w, _, _)| *w);
-    for (i, (w, _, _)) in rows.iter().enumerate() {
-        let expected = (i as u32) + 1;
-        if *w != expected {
-            return Err(format!(
-                "wave numbers must be continuous starting at 1; got {w} at position {expected}"
-            ));
+    if let Some(mut numbers) = numbers {
+        if numbers.is_empty() {
+            issues.sheet("no waves")?;
+            return Ok(None);
         }
+        numbers.sort_by_key(|(_, w)| *w);
+        for (i, (row_idx, w)) in numbers.iter().enumerate() {
+            let expected = (i as u32) + 1;
+            if *w != expected {
+                issues.cell(
+                    *row_idx,
+                    "wave",
+                    format!(
+                        "wave numbers must be continuous starting at 1; got {w} at position {expected}"
+                    ),
+                )?;
+                return Ok(None);
+            }
+        }
+    }
+    if !complete {
+        return Ok(None);
     }
+    rows.sort_by_key(|(_, w, _, _)| *w);
 
     let mut mob_unlocks: HashMap<MobKind, u32> = HashMap::new();
-    for (w, _, unlock) in &rows {
+    for (row_idx, w, _, unlock) in &rows {
         if let Some(kind) = unlock {
             if mob_unlocks.contains_key(kind) {
-                return Err(format!("mob {} unlocked more than once", kind.id()));
+                issues.cell(
+                    *row_idx,
+                    "unlocks",
+                    format!("mob {} unlocked more than once", kind.id()),
+                )?;
+                continue;
             }
             mob_unlocks.insert(*kind, *w);
         }
     }
 
-    for (w, def, _) in &rows {
+    for (_, w, def, _) in &rows {
         let unlocked_count = mob_unlocks.values().filter(|u| **u <= *w).count() as u32;
         if def.enemy_variety > unlocked_count {
             bevy::log::warn!(
@@ -177,60 +425,674 @@ pub fn parse_waves(range: &Range<Data>) -> Result<WavesConfig, BalanceError> {
         }
     }
 
-    let waves = rows.into_iter().map(|(_, d, _)| d).collect();
-    Ok(WavesConfig { mob_unlocks, waves })
+    let waves = rows.into_iter().map(|(_, _, d, _)| d).collect();
+    Ok(Some(WavesConfig { mob_unlocks, waves }))
+}
+
+fn weight_columns(
+    headers: &Headers,
+    issues: &mut SheetIssues,
+) -> Result<Option<WeightColumns>, BalanceError> {
+    let mut cols: Vec<(&String, usize)> = headers
+        .iter()
+        .filter(|(name, _)| name.starts_with("weight_"))
+        .map(|(name, col)| (name, *col))
+        .collect();
+    cols.sort_by_key(|(_, col)| *col);
+
+    let mut complete = true;
+    let mut out = Vec::new();
+    for (name, col) in cols {
+        match parse_mob_id(&name["weight_".len()..]) {
+            Ok(kind) => out.push((kind, col, name.clone())),
+            Err(e) => {
+                issues.cell(1, name, e)?;
+                complete = false;
+            }
+        }
+    }
+    Ok(complete.then_some(out))
+}
+
+fn parse_globals(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<Globals>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_key = required_col(&headers, "key", issues)?;
+    let c_value = required_col(&headers, "value", issues)?;
+    let (Some(c_key), Some(c_value)) = (c_key, c_value) else { return Ok(None) };
+
+    let mut map: HashMap<String, (usize, String)> = HashMap::new();
+    for (row_idx, row) in data_rows(range) {
+        let Some(key) = cell_str(row.get(c_key)) else {
+            issues.cell(row_idx, "key", "required")?;
+            continue;
+        };
+        let Some(value) = cell_str(row.get(c_value)) else {
+            issues.cell(row_idx, "value", format!("required for key {key}"))?;
+            continue;
+        };
+        if map.contains_key(&key) {
+            issues.cell(row_idx, "key", format!("duplicate key {key}"))?;
+            continue;
+        }
+        map.insert(key, (row_idx, value));
+    }
+
+    let mut get_f32 = |k: &str| -> Result<Option<f32>, BalanceError> {
+        match map.get(k) {
+            None => {
+                issues.sheet(format!("missing key {k}"))?;
+                Ok(None)
+            }
+            Some((row_idx, value)) => match value.parse::<f32>() {
+                Ok(v) => Ok(Some(v)),
+                Err(_) => {
+                    issues.cell(*row_idx, "value", format!("key {k}: not a float"))?;
+                    Ok(None)
+                }
+            },
+        }
+    };
+
+    let safe_spawn_radius = get_f32("safe_spawn_radius")?;
+    let arena_radius = get_f32("arena_radius")?;
+    let hp_growth = get_f32("endless_hp_growth")?;
+    let damage_growth = get_f32("endless_damage_growth")?;
+    let interval_floor = get_f32("endless_spawn_interval_floor")?;
+    let interval_approach = get_f32("endless_spawn_interval_approach")?;
+
+    let mut complete = true;
+    let mut check = |k: &str, v: Option<f32>, ok: fn(f32) -> bool, rule: &str| {
+        match v {
+            Some(v) if !ok(v) => {
+                complete = false;
+                issues.cell(map[k].0, "value", format!("key {k}: {rule}"))
+            }
+            Some(_) => Ok(()),
+            None => {
+                complete = false;
+                Ok(())
+            }
+        }
+    };
+    check("endless_hp_growth", hp_growth, |v| v > 0.0, "must be > 0")?;
+    check("endless_damage_growth", damage_growth, |v| v > 0.0, "must be > 0")?;
+    check("endless_spawn_interval_floor", interval_floor, |v| v > 0.0, "must be > 0")?;
+    check(
+        "endless_spawn_interval_approach",
+        interval_approach,
+        |v| (0.0..=1.0).contains(&v),
+        "must be in 0..=1",
+    )?;
+
+    if !complete {
+        return Ok(None);
+    }
+    let (
+        Some(safe_spawn_radius),
+        Some(arena_radius),
+        Some(hp_growth),
+        Some(damage_growth),
+        Some(spawn_interval_floor),
+        Some(spawn_interval_approach),
+    ) = (safe_spawn_radius, arena_radius, hp_growth, damage_growth, interval_floor, interval_approach)
+    else {
+        return Ok(None);
+    };
+    Ok(Some(Globals {
+        safe_spawn_radius,
+        arena_radius,
+        endless: EndlessScaling {
+            hp_growth,
+            damage_growth,
+            spawn_interval_floor,
+            spawn_interval_approach,
+        },
+    }))
+}
+
+const ABILITY_KEYS: &[&str] = &[
+    "fireball_damage_pct",
+    "fireball_speed",
+    "fireball_cooldown",
+    "fireball_size",
+    "fireball_gap",
+    "nova_cost",
+    "nova_cooldown",
+    "nova_damage_pct",
+    "nova_radius",
+];
+
+/// Zero here means a fireball every frame, or one that never moves or has no body.
+const POSITIVE_KEYS: &[&str] = &["fireball_speed", "fireball_cooldown", "fireball_size"];
+
+fn parse_player(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<PlayerBalance>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_key = required_col(&headers, "key", issues)?;
+    let c_modifier = required_col(&headers, "modifier", issues)?;
+    let c_value = required_col(&headers, "value", issues)?;
+    let (Some(c_key), Some(c_modifier), Some(c_value)) = (c_key, c_modifier, c_value) else {
+        return Ok(None);
+    };
+
+    let mut complete = true;
+    let mut base_stats = Vec::new();
+    let mut params: HashMap<&'static str, f32> = HashMap::new();
+    for (row_idx, row) in data_rows(range) {
+        let Some(key) = cell_str(row.get(c_key)) else {
+            issues.cell(row_idx, "key", "required")?;
+            complete = false;
+            continue;
+        };
+        let value = required_cell(issues, row_idx, row, c_value, "value", cell_f32)?;
+        let modifier = cell_str(row.get(c_modifier));
+        if let Some(&param) = ABILITY_KEYS.iter().find(|k| **k == key) {
+            if modifier.is_some() {
+                issues.cell(row_idx, "modifier", format!("not used by {key}"))?;
+            }
+            if params.contains_key(param) {
+                issues.cell(row_idx, "key", format!("duplicate key {key}"))?;
+                continue;
+            }
+            let positive = POSITIVE_KEYS.contains(&param);
+            match value {
+                Some(v) if v < 0.0 || (positive && v == 0.0) => {
+                    let cause = if positive { "must be > 0" } else { "must be >= 0" };
+                    issues.cell(row_idx, "value", cause)?;
+                    complete = false;
+                }
+                Some(v) => {
+                    params.insert(param, v);
+                }
+                None => complete = false,
+            }
+            continue;
+        }
+        let stat = match parse_stat_id(&key) {
+            Ok(stat) => Some(stat),
+            Err(e) => {
+                issues.cell(row_idx, "key", e)?;
+                None
+            }
+        };
+        let kind = match modifier.as_deref().map(parse_modifier_kind) {
+            Some(Ok(kind)) => Some(kind),
+            Some(Err(e)) => {
+                issues.cell(row_idx, "modifier", e)?;
+                None
+            }
+            None => {
+                issues.cell(row_idx, "modifier", format!("required for stat {key}"))?;
+                None
+            }
+        };
+        match (stat, kind, value) {
+            (Some(stat), Some(kind), Some(value)) => base_stats.push((stat, kind, value)),
+            _ => complete = false,
+        }
+    }
+
+    if !complete {
+        return Ok(None);
+    }
+    for key in ABILITY_KEYS {
+        if !params.contains_key(key) {
+            issues.sheet(format!("missing key {key}"))?;
+            complete = false;
+        }
+    }
+    if !complete {
+        return Ok(None);
+    }
+    Ok(Some(PlayerBalance {
+        base_stats,
+        fireball: FireballParams {
+            damage_pct: params["fireball_damage_pct"],
+            speed: params["fireball_speed"],
+            cooldown: params["fireball_cooldown"],
+            size: params["fireball_size"],
+            gap: params["fireball_gap"],
+        },
+        nova: NovaParams {
+            cost: params["nova_cost"],
+            cooldown: params["nova_cooldown"],
+            damage_pct: params["nova_damage_pct"],
+            radius: params["nova_radius"],
+        },
+    }))
+}
+
+fn parse_stats(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<StatsBalance>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_stat = required_col(&headers, "stat", issues)?;
+    let c_formula = required_col(&headers, "formula", issues)?;
+    let (Some(c_stat), Some(c_formula)) = (c_stat, c_formula) else { return Ok(None) };
+
+    let mut complete = true;
+    let mut formulas: Vec<(Stat, Formula)> = Vec::new();
+    for (row_idx, row) in data_rows(range) {
+        let stat = required_cell(issues, row_idx, row, c_stat, "stat", |c| {
+            cell_str(c).map(|s| parse_stat_id(&s)).transpose()
+        })?;
+        if let Some(stat) = stat {
+            if formulas.iter().any(|(s, _)| *s == stat) {
+                issues.cell(row_idx, "stat", format!("duplicate stat {}", stat.name()))?;
+                continue;
+            }
+        }
+        let formula = match cell_str(row.get(c_formula)) {
+            Some(id) => match parse_formula(&id, stat, row, &headers) {
+                Ok(formula) => Some(formula),
+                Err(e) => {
+                    issues.cell(row_idx, e.column, e.cause)?;
+                    None
+                }
+            },
+            None => {
+                issues.cell(row_idx, "formula", "required")?;
+                None
+            }
+        };
+        match (stat, formula) {
+            (Some(stat), Some(formula)) => formulas.push((stat, formula)),
+            _ => complete = false,
+        }
+    }
+    if !complete {
+        return Ok(None);
+    }
+    if let Err(e) = StatCalculators::try_with_formulas(&formulas) {
+        issues.sheet(e)?;
+        return Ok(None);
+    }
+    Ok(Some(StatsBalance { formulas }))
+}
+
+fn parse_formula(
+    id: &str,
+    stat: Option<Stat>,
+    row: &[Data],
+    headers: &Headers,
+) -> Result<Formula, CellError> {
+    let optional = |name: &'static str| {
+        cell_f32(headers.get(name).and_then(|&c| row.get(c))).map_err(|e| CellError::new(name, e))
+    };
+    match id {
+        "clamp" => {
+            let (min, max) = (optional("min")?, optional("max")?);
+            if min.is_none() && max.is_none() {
+                return Err(CellError::new("formula", "clamp needs a min or a max"));
+            }
+            let (min, max) = (min.unwrap_or(f32::NEG_INFINITY), max.unwrap_or(f32::INFINITY));
+            if min > max {
+                return Err(CellError::new("max", "must be >= min"));
+            }
+            Ok(Formula::Clamp { min, max })
+        }
+        "hyperbolic" => match param_f32(row, headers, "max")? {
+            max if max > 0.0 => Ok(Formula::Hyperbolic { max }),
+            _ => Err(CellError::new("max", "must be > 0")),
+        },
+        "cap" => {
+            let cap = param_f32(row, headers, "max")?;
+            let overflow = match headers.get("into").and_then(|&c| cell_str(row.get(c))) {
+                Some(into) => {
+                    let into = parse_stat_id(&into).map_err(|e| CellError::new("into", e))?;
+                    if Some(into) == stat {
+                        return Err(CellError::new("into", "stat overflows into itself"));
+                    }
+                    Some((into, optional("ratio")?.unwrap_or(1.0)))
+                }
+                None => None,
+            };
+            Ok(Formula::Capped { cap, overflow })
+        }
+        other => Err(CellError::new("formula", format!("unknown formula: {other}"))),
+    }
 }
 
-pub fn parse_globals(range: &Range<Data>) -> Result<Globals, BalanceError> {
-    let headers = parse_headers(range)?;
-    let c_key = required_col(&headers, "key")?;
-    let c_value = required_col(&headers, "value")?;
+fn parse_artifacts(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<ArtifactsBalance>, BalanceError> {
+    let Some(headers) = parse_headers(range, issues)? else { return Ok(None) };
+    let c_id = required_col(&headers, "id", issues)?;
+    let c_name = required_col(&headers, "name", issues)?;
+    let c_desc = required_col(&headers, "description", issues)?;
+    let c_effect = required_col(&headers, "effect", issues)?;
+    let c_tier = required_col(&headers, "tier", issues)?;
+    let c_replaces = headers.get("replaces").copied();
+    let c_condition = headers.get("condition").copied();
+    let (Some(c_id), Some(c_name), Some(c_desc), Some(c_effect), Some(c_tier)) =
+        (c_id, c_name, c_desc, c_effect, c_tier)
+    else {
+        return Ok(None);
+    };
 
-    let mut map: HashMap<String, String> = HashMap::new();
+    let mut complete = true;
+    let mut defs: HashMap<ArtifactKind, ArtifactDef> = HashMap::new();
     for (row_idx, row) in data_rows(range) {
-        let key = cell_str(row.get(c_key))
-            .ok_or_else(|| format!("row {row_idx}: empty key"))?;
-        let value = cell_str(row.get(c_value))
-            .ok_or_else(|| format!("row {row_idx}: value required for key {key}"))?;
-        if map.insert(key.clone(), value).is_some() {
-            return Err(format!("duplicate key {key}"));
+        let kind = required_cell(issues, row_idx, row, c_id, "id", |c| {
+            cell_str(c).map(|s| parse_artifact_id(&s)).transpose()
+        })?;
+        if let Some(kind) = kind {
+            if defs.contains_key(&kind) {
+                issues.cell(row_idx, "id", format!("duplicate artifact id {}", kind.id()))?;
+                continue;
+            }
+        }
+
+        let name = cell_str(row.get(c_name));
+        if name.is_none() {
+            issues.cell(row_idx, "name", "required")?;
+        }
+        let description = cell_str(row.get(c_desc)).unwrap_or_default();
+        let tier = required_cell(issues, row_idx, row, c_tier, "tier", |c| {
+            cell_str(c).map(|s| parse_artifact_tier(&s)).transpose()
+        })?;
+        let effect = match cell_str(row.get(c_effect)) {
+            Some(effect_id) => match parse_artifact_effect(&effect_id, row, &headers) {
+                Ok(effect) => Some(effect),
+                Err(e) => {
+                    issues.cell(row_idx, e.column, e.cause)?;
+                    None
+                }
+            },
+            None => {
+                issues.cell(row_idx, "effect", "required")?;
+                None
+            }
+        };
+
+        let condition = match c_condition.and_then(|c| cell_str(row.get(c))) {
+            Some(text) => match parse_condition(&text) {
+                Ok(_) if matches!(effect, Some(ArtifactEffect::Exotic(_))) => {
+                    issues.cell(row_idx, "condition", "exotic effects can't be conditional")?;
+                    None
+                }
+                Ok(_) if matches!(effect, Some(ArtifactEffect::PrimaryAttack(_))) => {
+                    issues.cell(row_idx, "condition", "primary attacks can't be conditional")?;
+                    None
+                }
+                Ok(condition) => Some(condition),
+                Err(e) => {
+                    issues.cell(row_idx, "condition", e)?;
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let mut replaces = Vec::new();
+        if let Some(list) = c_replaces.and_then(|c| cell_str(row.get(c))) {
+            for part in list.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                match parse_artifact_id(part) {
+                    Ok(r) if Some(r) == kind => {
+                        issues.cell(row_idx, "replaces", format!("{part} replaces itself"))?;
+                    }
+                    Ok(r) => replaces.push(r),
+                    Err(e) => issues.cell(row_idx, "replaces", e)?,
+                }
+            }
         }
+
+        let (Some(kind), Some(name), Some(tier), Some(effect)) = (kind, name, tier, effect) else {
+            complete = false;
+            continue;
+        };
+        defs.insert(kind, ArtifactDef { name, description, tier, effect, condition, replaces });
+    }
+    if !complete {
+        return Ok(None);
     }
 
-    let get_f32 = |k: &str| -> Result<f32, BalanceError> {
-        map.get(k)
-            .ok_or_else(|| format!("missing key {k}"))?
-            .parse::<f32>()
-            .map_err(|_| format!("key {k}: not a float"))
+    for &kind in ArtifactKind::ALL {
+        if !defs.contains_key(&kind) {
+            issues.sheet(format!("artifact {} missing", kind.id()))?;
+            complete = false;
+        }
+    }
+    if !complete {
+        return Ok(None);
+    }
+
+    Ok(Some(ArtifactsBalance { defs }))
+}
+
+fn parse_artifact_effect(
+    effect: &str,
+    row: &[Data],
+    headers: &Headers,
+) -> Result<ArtifactEffect, CellError> {
+    let f = |name: &'static str| param_f32(row, headers, name);
+    let u = |name: &'static str| param_u32(row, headers, name);
+    let stat_and_kind = || -> Result<(Stat, ModifierKind), CellError> {
+        let stat_id = headers
+            .get("stat")
+            .and_then(|c| cell_str(row.get(*c)))
+            .ok_or_else(|| CellError::new("stat", format!("required for {effect}")))?;
+        let modifier_id = headers
+            .get("modifier")
+            .and_then(|c| cell_str(row.get(*c)))
+            .ok_or_else(|| CellError::new("modifier", format!("required for {effect}")))?;
+        Ok((
+            parse_stat_id(&stat_id).map_err(|e| CellError::new("stat", e))?,
+            parse_modifier_kind(&modifier_id).map_err(|e| CellError::new("modifier", e))?,
+        ))
+    };
+    let e = match effect {
+        "stat_mod" => {
+            let (stat, kind) = stat_and_kind()?;
+            ArtifactEffect::StatMod { stat, kind, value: f("value")? }
+        }
+        "multishot" => ArtifactEffect::Multishot { extra: u("count")? },
+        "pierce" => ArtifactEffect::Pierce { extra: u("count")? },
+        "ricochet" => ArtifactEffect::Ricochet { count: u("count")? },
+        "homing" => ArtifactEffect::Homing { strength: f("value")? },
+        "splash" => ArtifactEffect::Splash { radius: f("radius")? },
+        "burn" => ArtifactEffect::OnHit(OnHitKind::Burn {
+            dps: f("value")?,
+            duration: f("duration")?,
+        }),
+        "freeze" => ArtifactEffect::OnHit(OnHitKind::Freeze {
+            chance: f("chance")?,
+            duration: f("duration")?,
+        }),
+        "lifesteal" => ArtifactEffect::OnHit(OnHitKind::Lifesteal { pct: f("value")? }),
+        "knockback" => ArtifactEffect::OnHit(OnHitKind::Knockback { force: f("value")? }),
+        "chain" => ArtifactEffect::OnHit(OnHitKind::Chain { count: u("count")? }),
+        "shield" => ArtifactEffect::Defensive(DefensiveKind::Shield {
+            max_block: f("value")?,
+            recharge: f("recharge")?,
+        }),
+        "dodge" => ArtifactEffect::Defensive(DefensiveKind::Dodge { chance: f("chance")? }),
+        "thorns" => ArtifactEffect::Defensive(DefensiveKind::Thorns {
+            reflect_pct: f("value")?,
+        }),
+        "turret" => ArtifactEffect::Exotic(ExoticKind::Turret {
+            fire_interval: f("interval")?,
+            damage_pct: f("value")?,
+        }),
+        "orbiting_orbs" => ArtifactEffect::Exotic(ExoticKind::OrbitingOrbs {
+            count: u("count")?,
+            radius: f("radius")?,
+            damage: f("value")?,
+        }),
+        "periodic_aoe" => ArtifactEffect::Exotic(ExoticKind::PeriodicAoe {
+            interval: f("interval")?,
+            radius: f("radius")?,
+            damage_pct: f("value")?,
+        }),
+        "beam" => ArtifactEffect::PrimaryAttack(PrimaryAttack::Beam {
+            damage_pct: f("value")?,
+            charge: f("duration")?,
+            length: f("range")?,
+            width: f("radius")?,
+        }),
+        "knife" => ArtifactEffect::PrimaryAttack(PrimaryAttack::Knife {
+            damage_pct: f("value")?,
+            range: f("range")?,
+            speed: f("speed")?,
+            cooldown: f("interval")?,
+        }),
+        "kill_explosion" => ArtifactEffect::OnKill(OnKillKind::Explosion {
+            damage_pct: f("value")?,
+            radius: f("radius")?,
+        }),
+        "kill_heal" => ArtifactEffect::OnKill(OnKillKind::Heal {
+            amount: f("value")?,
+            chance: f("chance")?,
+        }),
+        "kill_buff" => {
+            let (stat, kind) = stat_and_kind()?;
+            ArtifactEffect::OnKill(OnKillKind::Buff {
+                stat,
+                kind,
+                value: f("value")?,
+                duration: f("duration")?,
+                max_stacks: u("count")?,
+            })
+        }
+        other => return Err(CellError::new("effect", format!("unknown effect: {other}"))),
     };
+    Ok(e)
+}
 
-    Ok(Globals {
-        safe_spawn_radius: get_f32("safe_spawn_radius")?,
-        arena_radius: get_f32("arena_radius")?,
-    })
+fn parse_condition(text: &str) -> Result<Condition, String> {
+    let mut parts = text.split(':').map(str::trim);
+    let name = parts.next().unwrap_or_default();
+    let args: Vec<f32> = parts
+        .map(|p| p.parse::<f32>().map_err(|_| format!("bad number in condition: {p}")))
+        .collect::<Result<_, _>>()?;
+    let arity = |n: usize| {
+        if args.len() == n {
+            Ok(())
+        } else {
+            Err(format!("{name} takes {n} argument(s)"))
+        }
+    };
+    let fraction = |v: f32| {
+        if (0.0..=1.0).contains(&v) {
+            Ok(v)
+        } else {
+            Err(format!("{name} fraction must be in 0..=1"))
+        }
+    };
+    match name {
+        "life_above" => arity(1).and_then(|_| fraction(args[0])).map(Condition::LifeAbove),
+        "life_below" => arity(1).and_then(|_| fraction(args[0])).map(Condition::LifeBelow),
+        "moving" => arity(0).map(|_| Condition::Moving),
+        "stationary" => arity(0).map(|_| Condition::Stationary),
+        "recently_hit" => arity(1).map(|_| Condition::RecentlyHit(args[0])),
+        "enemies_within" => match args.as_slice() {
+            [radius] => Ok(Condition::EnemiesWithin { radius: *radius, count: 1 }),
+            [radius, count] if *count >= 1.0 && count.fract() == 0.0 => {
+                Ok(Condition::EnemiesWithin { radius: *radius, count: *count as u32 })
+            }
+            _ => Err("enemies_within takes a radius and an optional whole count".to_string()),
+        },
+        other => Err(format!("unknown condition: {other}")),
+    }
+}
+
+fn param_f32(row: &[Data], headers: &Headers, name: &'static str) -> Result<f32, CellError> {
+    let c = headers
+        .get(name)
+        .ok_or_else(|| CellError::new(name, "missing header"))?;
+    cell_f32(row.get(*c))
+        .map_err(|e| CellError::new(name, e))?
+        .ok_or_else(|| CellError::new(name, "required"))
+}
+
+fn param_u32(row: &[Data], headers: &Headers, name: &'static str) -> Result<u32, CellError> {
+    let c = headers
+        .get(name)
+        .ok_or_else(|| CellError::new(name, "missing header"))?;
+    cell_u32(row.get(*c))
+        .map_err(|e| CellError::new(name, e))?
+        .ok_or_else(|| CellError::new(name, "required"))
 }
 
-fn parse_headers(range: &Range<Data>) -> Result<HashMap<String, usize>, BalanceError> {
-    let mut header_row = range.rows();
-    let first = header_row.next().ok_or("empty sheet")?;
-    let mut out: HashMap<String, usize> = HashMap::new();
+fn parse_headers(
+    range: &Range<Data>,
+    issues: &mut SheetIssues,
+) -> Result<Option<Headers>, BalanceError> {
+    let Some(first) = range.rows().next() else {
+        issues.sheet("empty sheet")?;
+        return Ok(None);
+    };
+    let mut out: Headers = HashMap::new();
     for (idx, cell) in first.iter().enumerate() {
         let Some(name) = cell_str(Some(cell)) else { continue };
         if name.starts_with('_') {
             continue;
         }
-        if out.insert(name.clone(), idx).is_some() {
-            return Err(format!("duplicate header {name}"));
+        if out.contains_key(&name) {
+            issues.cell(1, &name, "duplicate header")?;
+            continue;
         }
+        out.insert(name, idx);
     }
-    Ok(out)
+    Ok(Some(out))
 }
 
-fn required_col(headers: &HashMap<String, usize>, name: &str) -> Result<usize, BalanceError> {
-    headers
-        .get(name)
-        .copied()
-        .ok_or_else(|| format!("missing header {name}"))
+fn required_col(
+    headers: &Headers,
+    name: &str,
+    issues: &mut SheetIssues,
+) -> Result<Option<usize>, BalanceError> {
+    let col = headers.get(name).copied();
+    if col.is_none() {
+        issues.cell(1, name, "missing header")?;
+    }
+    Ok(col)
+}
+
+fn required_cell<T>(
+    issues: &mut SheetIssues,
+    row_idx: usize,
+    row: &[Data],
+    col: usize,
+    name: &str,
+    parse: impl Fn(Option<&Data>) -> Result<Option<T>, String>,
+) -> Result<Option<T>, BalanceError> {
+    match parse(row.get(col)) {
+        Ok(Some(v)) => Ok(Some(v)),
+        Ok(None) => {
+            issues.cell(row_idx, name, "required")?;
+            Ok(None)
+        }
+        Err(e) => {
+            issues.cell(row_idx, name, e)?;
+            Ok(None)
+        }
+    }
+}
+
+fn optional_cell<T>(
+    issues: &mut SheetIssues,
+    row_idx: usize,
+    row: &[Data],
+    col: Option<usize>,
+    name: &str,
+    parse: impl Fn(Option<&Data>) -> Result<Option<T>, String>,
+) -> Result<Option<T>, BalanceError> {
+    let Some(col) = col else { return Ok(None) };
+    match parse(row.get(col)) {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            issues.cell(row_idx, name, e)?;
+            Ok(None)
+        }
+    }
 }
 
 fn data_rows<'a>(
@@ -257,7 +1119,7 @@ fn cell_str(cell: Option<&Data>) -> Option<String> {
     }
 }
 
-fn cell_f32(cell: Option<&Data>) -> Result<Option<f32>, BalanceError> {
+fn cell_f32(cell: Option<&Data>) -> Result<Option<f32>, String> {
     match cell {
         None | Some(Data::Empty) => Ok(None),
         Some(Data::Float(f)) => Ok(Some(*f as f32)),
@@ -276,7 +1138,11 @@ fn cell_f32(cell: Option<&Data>) -> Result<Option<f32>, BalanceError> {
     }
 }
 
-fn cell_u32(cell: Option<&Data>) -> Result<Option<u32>, BalanceError> {
+fn cell_count(cell: Option<&Data>) -> Result<Option<f32>, String> {
+    cell_u32(cell).map(|v| v.map(|v| v as f32))
+}
+
+fn cell_u32(cell: Option<&Data>) -> Result<Option<u32>, String> {
     match cell {
         None | Some(Data::Empty) => Ok(None),
         Some(Data::Int(i)) => {
@@ -307,12 +1173,44 @@ fn cell_u32(cell: Option<&Data>) -> Result<Option<u32>, BalanceError> {
     }
 }
 
-fn parse_mob_id(s: &str) -> Result<MobKind, BalanceError> {
+fn parse_mob_id(s: &str) -> Result<MobKind, String> {
     MobKind::iter()
         .find(|k| k.id() == s)
         .ok_or_else(|| format!("unknown mob id: {s}"))
 }
 
+fn parse_artifact_id(s: &str) -> Result<ArtifactKind, String> {
+    ArtifactKind::ALL
+        .iter()
+        .copied()
+        .find(|k| k.id() == s)
+        .ok_or_else(|| format!("unknown artifact id: {s}"))
+}
+
+fn parse_artifact_tier(s: &str) -> Result<ArtifactTier, String> {
+    ArtifactTier::ALL
+        .iter()
+        .copied()
+        .find(|t| t.id() == s)
+        .ok_or_else(|| format!("unknown artifact tier: {s}"))
+}
+
+fn parse_stat_id(s: &str) -> Result<Stat, String> {
+    Stat::iter()
+        .find(|k| k.name() == s)
+        .ok_or_else(|| format!("unknown stat: {s}"))
+}
+
+fn parse_modifier_kind(s: &str) -> Result<ModifierKind, String> {
+    match s {
+        "flat" => Ok(ModifierKind::Flat),
+        "increased" => Ok(ModifierKind::Increased),
+        "more" => Ok(ModifierKind::More),
+        other => Err(format!("unknown modifier kind: {other}")),
+    }
+}
+
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +1220,9 @@ mod tests {
         calamine::Range<Data>,
         calamine::Range<Data>,
         calamine::Range<Data>,
+        calamine::Range<Data>,
+        calamine::Range<Data>,
+        calamine::Range<Data>,
     ) {
         let path = "assets/balance.xlsx";
         let mut wb: Xlsx<_> = open_workbook(path).expect("open xlsx");
@@ -329,20 +1230,36 @@ mod tests {
             wb.worksheet_range("Mobs").expect("Mobs sheet"),
             wb.worksheet_range("Waves").expect("Waves sheet"),
             wb.worksheet_range("Globals").expect("Globals sheet"),
+            wb.worksheet_range("Artifacts").expect("Artifacts sheet"),
+            wb.worksheet_range("Player").expect("Player sheet"),
+            wb.worksheet_range("Stats").expect("Stats sheet"),
         )
     }
 
     #[test]
     fn happy_path_parses_real_xlsx() {
-        let (mobs, waves, globals) = load_real_workbook();
-        let bal = parse_balance(&mobs, &waves, &globals).expect("parse ok");
+        let (mobs, waves, globals, artifacts, player, stats) = load_real_workbook();
+        let bal = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .expect("parse ok");
 
-        assert!(bal.mobs.ghost.hp > 0.0);
-        assert!(bal.mobs.ghost.speed.is_some());
-        assert!(bal.mobs.tower.speed.is_none());
-        assert!(bal.mobs.spinner.speed.is_some());
-        assert!(bal.mobs.jumper.speed.is_some());
-        assert!(bal.mobs.slime_small.speed.is_some());
+        assert!(bal.mobs.ghost.stats.hp > 0.0);
+        assert!(bal.mobs.ghost.stats.speed.is_some());
+        assert!(bal.mobs.tower.stats.speed.is_none());
+        assert!(bal.mobs.spinner.stats.speed.is_some());
+        assert!(bal.mobs.jumper.stats.speed.is_some());
+        assert!(bal.mobs.slime_small.stats.speed.is_some());
+        assert_eq!(bal.mobs.jumper.behaviour.projectile_count, 4);
+        assert!(bal.mobs.spinner.behaviour.contact_radius > 0.0);
+        assert!(matches!(
+            bal.stats.formulas.iter().find(|(s, _)| *s == Stat::CritChance),
+            Some((_, Formula::Capped { overflow: Some((Stat::CritMultiplier, _)), .. }))
+        ));
+        assert!(matches!(
+            bal.artifacts.get(ArtifactKind::KillingSpree).effect,
+            ArtifactEffect::OnKill(OnKillKind::Buff { stat: Stat::PhysicalDamage, max_stacks: 10, .. })
+        ));
 
         assert!(!bal.waves.waves.is_empty());
         let first = &bal.waves.waves[0];
@@ -350,9 +1267,40 @@ mod tests {
         assert!(first.spawn_interval > 0.0);
         assert!(first.hp_multiplier > 0.0);
         assert!(first.damage_multiplier > 0.0);
+        assert_eq!(first.duration, 20.0);
+        let endless = &bal.globals.endless;
+        assert_eq!(bal.waves.for_wave(99, endless).duration, 60.0);
+        let last = bal.waves.waves.len() as u32;
+        let (at_end, past) =
+            (bal.waves.for_wave(last, endless), bal.waves.for_wave(last + 10, endless));
+        assert!(past.hp_multiplier > at_end.hp_multiplier);
+        assert!(past.spawn_interval < at_end.spawn_interval);
+        assert!(past.spawn_interval >= endless.spawn_interval_floor);
+        assert!(
+            bal.waves.for_wave(7, endless).weight(MobKind::SlimeSmall)
+                > bal.waves.for_wave(7, endless).weight(MobKind::Tower)
+        );
+        assert!(
+            bal.waves.for_wave(last, endless).tier_weight(ArtifactTier::Legendary)
+                > bal.waves.for_wave(1, endless).tier_weight(ArtifactTier::Legendary)
+        );
 
         assert!(bal.globals.safe_spawn_radius > 0.0);
         assert!(bal.globals.arena_radius > 0.0);
+
+        assert_eq!(bal.artifacts.defs.len(), ArtifactKind::ALL.len());
+        assert_eq!(bal.artifacts.get(ArtifactKind::BurningEdge).tier, ArtifactTier::Common);
+        assert!(matches!(
+            bal.artifacts.get(ArtifactKind::BurningEdge).effect,
+            ArtifactEffect::StatMod { stat: Stat::PhysicalDamage, .. }
+        ));
+
+        assert!(bal
+            .player
+            .base_stats
+            .iter()
+            .any(|(s, k, v)| *s == Stat::MaxLife && *k == ModifierKind::Flat && *v > 0.0));
+        assert!(bal.player.fireball.cooldown > 0.0);
     }
 
     #[test]
@@ -369,5 +1317,153 @@ mod tests {
         assert!(parse_mob_id("no_such_mob").is_err());
     }
 
+    #[test]
+    fn parse_artifact_ids_and_stats() {
+        assert_eq!(parse_artifact_id("runners_grace").unwrap(), ArtifactKind::RunnersGrace);
+        assert!(parse_artifact_id("no_such_artifact").is_err());
+        assert_eq!(parse_stat_id("crit_chance").unwrap(), Stat::CritChance);
+        assert!(parse_modifier_kind("sideways").is_err());
+        assert_eq!(parse_condition("life_above:0.8").unwrap(), Condition::LifeAbove(0.8));
+        assert_eq!(
+            parse_condition("enemies_within:300:3").unwrap(),
+            Condition::EnemiesWithin { radius: 300.0, count: 3 }
+        );
+        assert!(parse_condition("life_below").is_err());
+        assert!(parse_condition("life_below:1.5").is_err());
+    }
+
+    #[test]
+    fn fireball_speed_cooldown_and_size_must_be_positive() {
+        let (mobs, waves, globals, artifacts, mut player, stats) = load_real_workbook();
+        let keys: Vec<String> = player.rows().map(|r| r[0].to_string()).collect();
+        for key in ["fireball_speed", "fireball_cooldown", "fireball_size", "fireball_gap"] {
+            let row = keys.iter().position(|k| k == key).unwrap() as u32;
+            player.set_value((row, 2), Data::Float(0.0));
+        }
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .unwrap_err();
+        let causes: Vec<_> = err
+            .issues
+            .iter()
+            .map(|i| (i.sheet.as_deref(), i.cause.as_str()))
+            .collect();
+        assert_eq!(causes, vec![(Some("Player"), "must be > 0"); 3]);
+    }
+
+    #[test]
+    fn one_shadow_veil_dodges_twenty_percent() {
+        use crate::stats::{ComputedStats, DirtyStats, Modifiers};
+
+        let (mobs, waves, globals, artifacts, player, stats) = load_real_workbook();
+        let bal = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .expect("parse ok");
+        let ArtifactEffect::Defensive(DefensiveKind::Dodge { chance }) =
+            bal.artifacts.get(ArtifactKind::ShadowVeil).effect
+        else {
+            panic!("shadow veil is not a dodge");
+        };
+        let calc = StatCalculators::with_formulas(&bal.stats.formulas);
+        let mut modifiers = Modifiers::new();
+        modifiers.add(Stat::DodgeChance, ModifierKind::Flat, chance);
+        let mut cs = ComputedStats::default();
+        let mut dirty = DirtyStats::default();
+        dirty.mark_all(Stat::iter());
+        calc.recalculate(&modifiers, &mut cs, &mut dirty);
+        assert!((cs.final_of(Stat::DodgeChance) - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mob_behaviour_defaults_and_misplaced_columns() {
+        let (mut mobs, waves, globals, artifacts, player, stats) = load_real_workbook();
+        let bal = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .expect("parse ok");
+        assert_eq!(
+            bal.mobs.spinner.behaviour.windup_weight,
+            SpinnerBehaviour::default().windup_weight
+        );
+
+        let headers: Vec<String> =
+            mobs.rows().next().unwrap().iter().map(|c| c.to_string()).collect();
+        let col = |name: &str| headers.iter().position(|h| h == name).unwrap() as u32;
+        mobs.set_value((4, col("spread")), Data::Empty);
+        mobs.set_value((1, col("lunge_duration")), Data::Float(1.0));
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].column.as_deref(), Some("lunge_duration"));
+    }
+
+    #[test]
+    fn ghost_distances_must_leave_a_fade_range() {
+        let (mut mobs, waves, globals, artifacts, player, stats) = load_real_workbook();
+        let headers: Vec<String> =
+            mobs.rows().next().unwrap().iter().map(|c| c.to_string()).collect();
+        let col = |name: &str| headers.iter().position(|h| h == name).unwrap() as u32;
+        mobs.set_value((1, col("invisible_distance")), Data::Float(150.0));
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].column.as_deref(), Some("invisible_distance"));
+    }
+
+    #[test]
+    fn spinner_phase_weights_must_not_all_be_zero() {
+        let (mut mobs, waves, globals, artifacts, player, stats) = load_real_workbook();
+        let headers: Vec<String> =
+            mobs.rows().next().unwrap().iter().map(|c| c.to_string()).collect();
+        let col = |name: &str| headers.iter().position(|h| h == name).unwrap() as u32;
+        for name in ["idle_weight", "windup_weight", "charge_weight", "cooldown_weight"] {
+            mobs.set_value((5, col(name)), Data::Float(0.0));
+        }
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+        assert_eq!(err.issues[0].row, Some(6));
+        assert_eq!(err.issues[0].column.as_deref(), Some("idle_weight"));
+    }
+
+    #[test]
+    fn collect_all_reports_every_bad_cell() {
+        let (mut mobs, mut waves, globals, artifacts, player, stats) = load_real_workbook();
+        mobs.set_value((1, 1), Data::String("lots".into()));
+        waves.set_value((3, 3), Data::Float(-1.0));
+        waves.set_value((5, 4), Data::String("x".into()));
+
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::CollectAll,
+        )
+        .unwrap_err();
+        let located: Vec<_> = err
+            .issues
+            .iter()
+            .map(|i| (i.sheet.as_deref(), i.row, i.column.as_deref()))
+            .collect();
+        assert_eq!(
+            located,
+            vec![
+                (Some("Mobs"), Some(2), Some("hp")),
+                (Some("Waves"), Some(4), Some("spawn_interval")),
+                (Some("Waves"), Some(6), Some("hp_multiplier")),
+            ]
+        );
+
+        let err = parse_balance(
+            &mobs, &waves, &globals, &artifacts, &player, &stats, ParseMode::FailFast,
+        )
+        .unwrap_err();
+        assert_eq!(err.issues.len(), 1);
+    }
 }
 
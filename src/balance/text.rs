@@ -0,0 +1,113 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use calamine::{Data, Range};
+use ron::Value;
+use serde::Deserialize;
+
+use super::error::{BalanceError, ParseMode};
+use super::loader::load_from_sheets;
+use super::types::Balance;
+
+pub const EXTENSION: &str = "ron";
+
+#[derive(Deserialize)]
+struct SheetFile {
+    columns: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+pub fn sheet_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(name).with_extension(EXTENSION)
+}
+
+pub fn load_text_dir(dir: impl AsRef<Path>, mode: ParseMode) -> Result<Balance, BalanceError> {
+    let dir = dir.as_ref();
+    load_from_sheets(|name| read_sheet_file(&sheet_path(dir, name)), mode)
+}
+
+pub fn read_sheet_file(path: &Path) -> Result<Range<Data>, String> {
+    let text =
+        std::fs::read_to_string(path).map_err(|e| format!("reading {}: {e}", path.display()))?;
+    parse_sheet(&text).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+pub fn parse_sheet(text: &str) -> Result<Range<Data>, String> {
+    let file: SheetFile = ron::from_str(text).map_err(|e| e.to_string())?;
+    let width = file
+        .rows
+        .iter()
+        .map(Vec::len)
+        .chain(std::iter::once(file.columns.len()))
+        .max()
+        .unwrap_or(0);
+    if width == 0 {
+        return Ok(Range::empty());
+    }
+    let mut range = Range::new((0, 0), (file.rows.len() as u32, width as u32 - 1));
+    for (col, name) in file.columns.iter().enumerate() {
+        if !name.is_empty() {
+            range.set_value((0, col as u32), Data::String(name.clone()));
+        }
+    }
+    for (row_idx, row) in file.rows.into_iter().enumerate() {
+        for (col, value) in row.into_iter().enumerate() {
+            let cell = value_to_cell(value)
+                .map_err(|e| format!("row {} column {}: {e}", row_idx + 2, col + 1))?;
+            range.set_value((row_idx as u32 + 1, col as u32), cell);
+        }
+    }
+    Ok(range)
+}
+
+fn value_to_cell(value: Value) -> Result<Data, String> {
+    match value {
+        Value::Unit => Ok(Data::Empty),
+        Value::Bool(b) => Ok(Data::Bool(b)),
+        Value::Number(n) => Ok(Data::Float(n.into_f64())),
+        Value::String(s) => Ok(Data::String(s)),
+        Value::Char(c) => Ok(Data::String(c.to_string())),
+        other => Err(format!("unsupported cell value {other:?}")),
+    }
+}
+
+pub fn write_sheet(range: &Range<Data>) -> String {
+    let mut rows = range.rows();
+    let columns: Vec<String> = rows
+        .next()
+        .map(|header| header.iter().map(cell_text).collect())
+        .unwrap_or_default();
+
+    let mut out = String::from("(\n    columns: [");
+    out.push_str(&columns.iter().map(|c| quote(c)).collect::<Vec<_>>().join(", "));
+    out.push_str("],\n    rows: [\n");
+    for row in rows {
+        let used = row.iter().rposition(|c| !matches!(c, Data::Empty)).map_or(0, |i| i + 1);
+        let cells: Vec<String> = row[..used].iter().map(cell_value).collect();
+        let _ = writeln!(out, "        [{}],", cells.join(", "));
+    }
+    out.push_str("    ],\n)\n");
+    out
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn cell_value(cell: &Data) -> String {
+    match cell {
+        Data::Empty => "()".to_string(),
+        Data::Bool(b) => b.to_string(),
+        Data::Int(i) => i.to_string(),
+        Data::Float(f) => f.to_string(),
+        Data::String(s) => quote(s),
+        other => quote(&other.to_string()),
+    }
+}
+
+fn quote(s: &str) -> String {
+    ron::to_string(s).unwrap_or_else(|_| format!("{s:?}"))
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use bevy::prelude::*;
@@ -5,6 +6,8 @@ use rand::seq::SliceRandom;
 use rand::Rng;
 
 use crate::actors::MobKind;
+use crate::artifact::{ArtifactDef, ArtifactKind, ArtifactTier};
+use crate::stats::{Formula, ModifierKind, Stat};
 
 #[derive(Debug, Clone)]
 pub struct MobCommonStats {
@@ -16,23 +19,98 @@ pub struct MobCommonStats {
     pub attack_speed: Option<f32>,
 }
 
+#[derive(Debug, Clone)]
+pub struct MobBalance<B> {
+    pub stats: MobCommonStats,
+    pub behaviour: B,
+}
+
+#[derive(Debug, Clone)]
+pub struct GhostBehaviour {
+    pub visible_distance: f32,
+    pub invisible_distance: f32,
+}
+
+impl Default for GhostBehaviour {
+    fn default() -> Self {
+        Self { visible_distance: 150.0, invisible_distance: 400.0 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SlimeBehaviour {
+    pub lunge_duration: f32,
+}
+
+impl Default for SlimeBehaviour {
+    fn default() -> Self {
+        Self { lunge_duration: 0.5 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TowerBehaviour {
+    pub explosion_radius: f32,
+    pub spread: f32,
+}
+
+impl Default for TowerBehaviour {
+    fn default() -> Self {
+        Self { explosion_radius: 400.0, spread: 450.0 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JumperBehaviour {
+    pub projectile_count: u32,
+    pub projectile_speed: f32,
+    pub spread_degrees: f32,
+}
+
+impl Default for JumperBehaviour {
+    fn default() -> Self {
+        Self { projectile_count: 4, projectile_speed: 400.0, spread_degrees: 45.0 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpinnerBehaviour {
+    pub idle_weight: f32,
+    pub windup_weight: f32,
+    pub charge_weight: f32,
+    pub cooldown_weight: f32,
+    pub contact_radius: f32,
+}
+
+impl Default for SpinnerBehaviour {
+    fn default() -> Self {
+        Self {
+            idle_weight: 10.0,
+            windup_weight: 60.0,
+            charge_weight: 20.0,
+            cooldown_weight: 10.0,
+            contact_radius: 150.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Resource)]
 pub struct MobsBalance {
-    pub ghost: MobCommonStats,
-    pub tower: MobCommonStats,
-    pub slime_small: MobCommonStats,
-    pub jumper: MobCommonStats,
-    pub spinner: MobCommonStats,
+    pub ghost: MobBalance<GhostBehaviour>,
+    pub tower: MobBalance<TowerBehaviour>,
+    pub slime_small: MobBalance<SlimeBehaviour>,
+    pub jumper: MobBalance<JumperBehaviour>,
+    pub spinner: MobBalance<SpinnerBehaviour>,
 }
 
 impl MobsBalance {
     pub fn get(&self, kind: MobKind) -> &MobCommonStats {
         match kind {
-            MobKind::Ghost => &self.ghost,
-            MobKind::Tower => &self.tower,
-            MobKind::SlimeSmall => &self.slime_small,
-            MobKind::Spinner => &self.spinner,
-            MobKind::Jumper => &self.jumper,
+            MobKind::Ghost => &self.ghost.stats,
+            MobKind::Tower => &self.tower.stats,
+            MobKind::SlimeSmall => &self.slime_small.stats,
+            MobKind::Spinner => &self.spinner.stats,
+            MobKind::Jumper => &self.jumper.stats,
         }
     }
 }
@@ -43,6 +121,53 @@ pub struct WaveDef {
     pub spawn_interval: f32,
     pub hp_multiplier: f32,
     pub damage_multiplier: f32,
+    pub duration: f32,
+    pub breather: f32,
+    pub weights: HashMap<MobKind, f32>,
+    pub tier_weights: HashMap<ArtifactTier, f32>,
+}
+
+impl WaveDef {
+    pub fn weight(&self, kind: MobKind) -> f32 {
+        self.weights.get(&kind).copied().unwrap_or(1.0)
+    }
+
+    pub fn tier_weight(&self, tier: ArtifactTier) -> f32 {
+        self.tier_weights.get(&tier).copied().unwrap_or(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EndlessScaling {
+    pub hp_growth: f32,
+    pub damage_growth: f32,
+    pub spawn_interval_floor: f32,
+    pub spawn_interval_approach: f32,
+}
+
+impl Default for EndlessScaling {
+    fn default() -> Self {
+        Self {
+            hp_growth: 1.0,
+            damage_growth: 1.0,
+            spawn_interval_floor: 0.0,
+            spawn_interval_approach: 0.0,
+        }
+    }
+}
+
+impl EndlessScaling {
+    pub fn extrapolate(&self, last: &WaveDef, waves_past: u32) -> WaveDef {
+        let n = waves_past as i32;
+        let floor = self.spawn_interval_floor.min(last.spawn_interval);
+        let remaining = (1.0 - self.spawn_interval_approach).powi(n);
+        WaveDef {
+            hp_multiplier: last.hp_multiplier * self.hp_growth.powi(n),
+            damage_multiplier: last.damage_multiplier * self.damage_growth.powi(n),
+            spawn_interval: floor + (last.spawn_interval - floor) * remaining,
+            ..last.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone, Resource)]
@@ -52,20 +177,32 @@ pub struct WavesConfig {
 }
 
 impl WavesConfig {
-    pub fn for_wave(&self, wave: u32) -> &WaveDef {
+    /// Borrows the sheet row; only endless waves past the last row build a new def.
+    pub fn for_wave(&self, wave: u32, endless: &EndlessScaling) -> Cow<'_, WaveDef> {
+        let last = self.waves.len() as u32;
         let idx = (wave.saturating_sub(1) as usize).min(self.waves.len().saturating_sub(1));
-        &self.waves[idx]
+        if wave <= last {
+            Cow::Borrowed(&self.waves[idx])
+        } else {
+            Cow::Owned(endless.extrapolate(&self.waves[idx], wave - last))
+        }
     }
 
     pub fn unlock_wave(&self, kind: MobKind) -> u32 {
         self.mob_unlocks.get(&kind).copied().unwrap_or(0)
     }
 
-    pub fn resolve_pool(&self, wave: u32, rng: &mut impl Rng) -> Vec<MobKind> {
+    pub fn resolve_pool(
+        &self,
+        wave: u32,
+        endless: &EndlessScaling,
+        rng: &mut impl Rng,
+    ) -> Vec<(MobKind, f32)> {
+        let def = self.for_wave(wave, endless);
         let unlocked: Vec<MobKind> = MobKind::iter()
             .filter(|k| {
                 let u = self.unlock_wave(*k);
-                u > 0 && u <= wave
+                u > 0 && u <= wave && def.weight(*k) > 0.0
             })
             .collect();
         let mut picked: Vec<MobKind> = unlocked
@@ -76,13 +213,13 @@ impl WavesConfig {
                 u == wave || u + 1 == wave
             })
             .collect();
-        let variety = self.for_wave(wave).enemy_variety as usize;
+        let variety = def.enemy_variety as usize;
         let mut remaining: Vec<MobKind> =
             unlocked.into_iter().filter(|k| !picked.contains(k)).collect();
         remaining.shuffle(rng);
         let need = variety.saturating_sub(picked.len());
         picked.extend(remaining.into_iter().take(need));
-        picked
+        picked.into_iter().map(|k| (k, def.weight(k))).collect()
     }
 }
 
@@ -90,6 +227,47 @@ impl WavesConfig {
 pub struct Globals {
     pub safe_spawn_radius: f32,
     pub arena_radius: f32,
+    pub endless: EndlessScaling,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FireballParams {
+    pub damage_pct: f32,
+    pub speed: f32,
+    pub cooldown: f32,
+    pub size: f32,
+    pub gap: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NovaParams {
+    pub cost: f32,
+    pub cooldown: f32,
+    pub damage_pct: f32,
+    pub radius: f32,
+}
+
+#[derive(Debug, Clone, Resource)]
+pub struct PlayerBalance {
+    pub base_stats: Vec<(Stat, ModifierKind, f32)>,
+    pub fireball: FireballParams,
+    pub nova: NovaParams,
+}
+
+#[derive(Debug, Clone, Resource)]
+pub struct ArtifactsBalance {
+    pub defs: HashMap<ArtifactKind, ArtifactDef>,
+}
+
+impl ArtifactsBalance {
+    pub fn get(&self, kind: ArtifactKind) -> &ArtifactDef {
+        &self.defs[&kind]
+    }
+}
+
+#[derive(Debug, Clone, Resource)]
+pub struct StatsBalance {
+    pub formulas: Vec<(Stat, Formula)>,
 }
 
 #[derive(Debug, Clone, Resource)]
@@ -97,4 +275,7 @@ pub struct Balance {
     pub mobs: MobsBalance,
     pub waves: WavesConfig,
     pub globals: Globals,
+    pub artifacts: ArtifactsBalance,
+    pub player: PlayerBalance,
+    pub stats: StatsBalance,
 }
@@ -0,0 +1,160 @@
+use std::process::ExitCode;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use magic_craft_bevy::actors::MobKind;
+use magic_craft_bevy::balance::check::cross_check;
+use magic_craft_bevy::balance::error::{BalanceError, ParseMode};
+use magic_craft_bevy::balance::loader::load_path;
+use magic_craft_bevy::balance::types::Balance;
+
+const DEFAULT_PATH: &str = "assets/balance.xlsx";
+const ENDLESS_PREVIEW: &[u32] = &[1, 2, 3, 5, 10, 20, 30, 50, 100];
+const USAGE: &str = "usage: balance-check [path/to/balance.xlsx | path/to/dir] [--seed N]";
+
+struct Args {
+    path: String,
+    seed: u64,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args { path: DEFAULT_PATH.to_string(), seed: 0 };
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = it.next().ok_or("--seed requires a value")?;
+                args.seed = value.parse().map_err(|_| format!("invalid seed {value:?}"))?;
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => args.path = arg,
+        }
+    }
+    Ok(args)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let balance = match load_path(&args.path, ParseMode::CollectAll) {
+        Ok(balance) => balance,
+        Err(e) => {
+            eprintln!("{}: {e}", args.path);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print_player(&balance);
+    println!();
+    print_mobs(&balance);
+    println!();
+    print_waves(&balance, args.seed);
+    println!();
+    print_endless(&balance);
+    println!();
+
+    let issues = cross_check(&balance);
+    if !issues.is_empty() {
+        eprintln!("{}: {}", args.path, BalanceError { issues });
+        return ExitCode::FAILURE;
+    }
+    println!("{}: ok", args.path);
+    ExitCode::SUCCESS
+}
+
+fn opt(v: Option<f32>) -> String {
+    v.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+fn print_player(balance: &Balance) {
+    println!("Player");
+    for (stat, kind, value) in &balance.player.base_stats {
+        println!("  {:<20} {:<10} {}", stat.name(), format!("{kind:?}"), value);
+    }
+    println!("  fireball: {:?}", balance.player.fireball);
+}
+
+fn print_mobs(balance: &Balance) {
+    println!("Mobs");
+    println!(
+        "  {:<12} {:>7} {:>7} {:>7} {:>7} {:>7} {:>12}",
+        "id", "hp", "damage", "speed", "size", "mass", "attack_speed"
+    );
+    for kind in MobKind::iter() {
+        let m = balance.mobs.get(kind);
+        println!(
+            "  {:<12} {:>7} {:>7} {:>7} {:>7} {:>7} {:>12}",
+            kind.id(),
+            m.hp,
+            m.damage,
+            opt(m.speed),
+            m.size,
+            opt(m.mass),
+            opt(m.attack_speed),
+        );
+    }
+    let mobs = &balance.mobs;
+    println!("  behaviour:");
+    println!("    {:<12} {:?}", MobKind::Ghost.id(), mobs.ghost.behaviour);
+    println!("    {:<12} {:?}", MobKind::Tower.id(), mobs.tower.behaviour);
+    println!("    {:<12} {:?}", MobKind::SlimeSmall.id(), mobs.slime_small.behaviour);
+    println!("    {:<12} {:?}", MobKind::Jumper.id(), mobs.jumper.behaviour);
+    println!("    {:<12} {:?}", MobKind::Spinner.id(), mobs.spinner.behaviour);
+}
+
+fn print_waves(balance: &Balance, seed: u64) {
+    let waves = &balance.waves;
+    let mut rng = StdRng::seed_from_u64(seed);
+    println!("Waves (pool seed {seed})");
+    println!(
+        "  {:>4} {:<12} {:>7} {:>8} {:>6} {:>6} {:>8} {:>8}  pool",
+        "wave", "unlocks", "variety", "interval", "hp_x", "dmg_x", "duration", "breather"
+    );
+    for (i, def) in waves.waves.iter().enumerate() {
+        let wave = i as u32 + 1;
+        let unlocks: Vec<&str> = MobKind::iter()
+            .filter(|k| waves.unlock_wave(*k) == wave)
+            .map(MobKind::id)
+            .collect();
+        let pool: Vec<String> = waves
+            .resolve_pool(wave, &balance.globals.endless, &mut rng)
+            .into_iter()
+            .map(|(kind, weight)| format!("{}:{weight}", kind.id()))
+            .collect();
+        println!(
+            "  {:>4} {:<12} {:>7} {:>8} {:>6} {:>6} {:>8} {:>8}  {}",
+            wave,
+            if unlocks.is_empty() { "-".to_string() } else { unlocks.join(",") },
+            def.enemy_variety,
+            def.spawn_interval,
+            def.hp_multiplier,
+            def.damage_multiplier,
+            def.duration,
+            def.breather,
+            pool.join(", "),
+        );
+    }
+}
+
+fn print_endless(balance: &Balance) {
+    let waves = &balance.waves;
+    let last = waves.waves.len() as u32;
+    let endless = &balance.globals.endless;
+    println!("Endless (past wave {last}): {endless:?}");
+    println!("  {:>4} {:>8} {:>8} {:>8}", "wave", "interval", "hp_x", "dmg_x");
+    for extra in ENDLESS_PREVIEW {
+        let wave = last + extra;
+        let def = waves.for_wave(wave, endless);
+        println!(
+            "  {:>4} {:>8.3} {:>8.3} {:>8.3}",
+            wave, def.spawn_interval, def.hp_multiplier, def.damage_multiplier,
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use std::path::Path;
+use std::process::ExitCode;
+
+use magic_craft_bevy::balance::convert::{
+    read_text_sheets, read_xlsx_sheets, validate, write_text_sheets, write_xlsx,
+};
+use magic_craft_bevy::balance::error::ParseMode;
+
+const USAGE: &str = "usage: balance-convert <balance.xlsx> <dir> | <dir> <balance.xlsx>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [from, to] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+    let (from, to) = (Path::new(from), Path::new(to));
+
+    let to_text = !from.is_dir();
+    let sheets = if to_text { read_xlsx_sheets(from) } else { read_text_sheets(from) };
+    let sheets = match sheets {
+        Ok(sheets) => sheets,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = validate(&sheets, ParseMode::CollectAll) {
+        eprintln!("{}: {e}", from.display());
+        return ExitCode::FAILURE;
+    }
+
+    let written = if to_text { write_text_sheets(to, &sheets) } else { write_xlsx(to, &sheets) };
+    if let Err(e) = written {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    println!("{} -> {}: {} sheets", from.display(), to.display(), sheets.len());
+    ExitCode::SUCCESS
+}
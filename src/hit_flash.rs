@@ -19,6 +19,12 @@ impl HitFlash {
     }
 }
 
+impl Default for HitFlash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Resource)]
 struct HitFlashScaleLayer(ScaleLayerId);
 
@@ -10,6 +10,7 @@ pub struct PlayerIntent {
     pub move_dir: Vec2,
     pub aim_dir: Vec2,
     pub fire: bool,
+    pub ability: bool,
 }
 
 pub struct PlayerInputPlugin;
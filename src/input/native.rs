@@ -1,4 +1,4 @@
-// Desktop (native) backend for `PlayerInputPlugin`: reads WASD + mouse cursor + LMB,
+// Desktop (native) backend for `PlayerInputPlugin`: reads WASD + mouse cursor + LMB/RMB,
 // writes to `PlayerIntent`. Aim direction is computed by ray-casting the mouse cursor
 // onto the ground plane and subtracting the player position.
 
@@ -41,6 +41,7 @@ fn gather(
     }
     intent.move_dir = raw_move.normalize_or_zero();
     intent.fire = mouse.pressed(MouseButton::Left);
+    intent.ability = mouse.pressed(MouseButton::Right);
 
     let mut aim = Vec2::ZERO;
     'aim: {
@@ -21,6 +21,7 @@ use crate::wave::CombatPhase;
 const STICK_RADIUS_PX: f32 = 80.0;
 const KNOB_SIZE_PX: f32 = 60.0;
 const OUTLINE_SIZE_PX: f32 = 160.0;
+const ABILITY_BUTTON_PX: f32 = 96.0;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum StickId {
@@ -42,6 +43,9 @@ struct TouchStick {
     current_local: Vec2,
 }
 
+#[derive(Component)]
+struct AbilityButton;
+
 #[derive(Component)]
 struct StickOutline {
     stick: Entity,
@@ -71,6 +75,23 @@ fn reset_intent(mut intent: ResMut<PlayerIntent>) {
 fn spawn_sticks(mut commands: Commands) {
     spawn_stick(&mut commands, StickId::Move, true);
     spawn_stick(&mut commands, StickId::Aim, false);
+    commands.spawn((
+        Name::new("AbilityButton"),
+        DespawnOnExit(GameState::Playing),
+        AbilityButton,
+        Button,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(ABILITY_BUTTON_PX),
+            height: Val::Px(ABILITY_BUTTON_PX),
+            right: Val::Px(32.0),
+            bottom: Val::Percent(62.0),
+            border_radius: BorderRadius::all(Val::Px(ABILITY_BUTTON_PX / 2.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.3, 0.8, 0.85, 0.45)),
+        GlobalZIndex(31),
+    ));
 }
 
 fn spawn_stick(commands: &mut Commands, id: StickId, left_side: bool) {
@@ -238,7 +259,12 @@ fn stick_axis(stick: &TouchStick) -> Vec2 {
     Vec2::new(dir.x, -dir.y) * mag
 }
 
-fn write_intent(sticks: Query<&TouchStick>, mut intent: ResMut<PlayerIntent>) {
+fn write_intent(
+    sticks: Query<&TouchStick>,
+    ability_button: Query<&Interaction, With<AbilityButton>>,
+    mut intent: ResMut<PlayerIntent>,
+) {
+    intent.ability = ability_button.iter().any(|i| *i == Interaction::Pressed);
     for stick in &sticks {
         let axis = stick_axis(stick);
         match stick.id {
@@ -0,0 +1,25 @@
+pub mod actors;
+pub mod artifact;
+pub mod balance;
+pub mod composite_scale;
+pub mod arena;
+pub mod dissolve_material;
+pub mod faction;
+pub mod game_state;
+pub mod health_material;
+pub mod hit_flash;
+pub mod input;
+pub mod loading;
+pub mod coord;
+pub mod palette;
+pub mod particles;
+pub mod run;
+pub mod schedule;
+pub mod stats;
+pub mod transition;
+pub mod ui;
+pub mod wave;
+
+pub use faction::Faction;
+pub use game_state::GameState;
+pub use transition::{Transition, TransitionAction};
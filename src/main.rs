@@ -1,29 +1,3 @@
-mod actors;
-mod artifact;
-mod balance;
-mod composite_scale;
-mod arena;
-mod dissolve_material;
-mod faction;
-mod game_state;
-mod health_material;
-mod hit_flash;
-mod input;
-mod loading;
-mod coord;
-pub mod palette;
-mod particles;
-mod run;
-mod schedule;
-mod stats;
-mod transition;
-mod ui;
-mod wave;
-
-pub use faction::Faction;
-pub use game_state::GameState;
-pub use transition::{Transition, TransitionAction};
-
 fn disable_physics_debug(mut store: ResMut<GizmoConfigStore>) {
     store.config_mut::<PhysicsGizmos>().0.enabled = false;
 }
@@ -38,24 +12,25 @@ fn toggle_physics_debug(key: Res<ButtonInput<KeyCode>>, mut store: ResMut<GizmoC
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
-use actors::ActorsPlugin;
-use arena::ArenaPlugin;
-use artifact::ArtifactPlugin;
-use balance::BalancePlugin;
-use dissolve_material::DissolveMaterialPlugin;
-use health_material::HealthMaterialPlugin;
-use hit_flash::HitFlashPlugin;
-use input::PlayerInputPlugin;
-use loading::LoadingPlugin;
-use schedule::{GameSet, MovementSet, PostGameSet};
-use stats::StatsPlugin;
-use transition::TransitionPlugin;
-use ui::UiPlugin;
+use magic_craft_bevy::actors::ActorsPlugin;
+use magic_craft_bevy::arena::ArenaPlugin;
+use magic_craft_bevy::artifact::ArtifactPlugin;
+use magic_craft_bevy::balance::BalancePlugin;
+use magic_craft_bevy::dissolve_material::DissolveMaterialPlugin;
+use magic_craft_bevy::health_material::HealthMaterialPlugin;
+use magic_craft_bevy::hit_flash::HitFlashPlugin;
+use magic_craft_bevy::input::PlayerInputPlugin;
+use magic_craft_bevy::loading::LoadingPlugin;
+use magic_craft_bevy::schedule::{GameSet, MovementSet, PostGameSet};
+use magic_craft_bevy::stats::StatsPlugin;
+use magic_craft_bevy::transition::TransitionPlugin;
+use magic_craft_bevy::ui::UiPlugin;
 use bevy_tweening::TweeningPlugin;
-use run::RunPlugin;
-use wave::{CombatPhase, WavePlugin};
+use magic_craft_bevy::run::RunPlugin;
+use magic_craft_bevy::wave::{CombatPhase, WavePlugin};
+use magic_craft_bevy::{composite_scale, particles, GameState};
 
-use arena::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use magic_craft_bevy::arena::{WINDOW_HEIGHT, WINDOW_WIDTH};
 use bevy::window::WindowResolution;
 
 #[cfg(all(feature = "dev", not(target_arch = "wasm32")))]
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::balance::{Globals, WavesConfig};
 use crate::composite_scale::ScaleModifiers;
 use crate::game_state::GameState;
 use crate::wave::{CombatPhase, WaveEnemy};
@@ -18,12 +19,6 @@ pub struct BreatherTimer(pub Timer);
 #[derive(Message)]
 pub struct StartWaveEvent;
 
-pub fn wave_duration(_wave: u32) -> f32 {
-    30.0
-}
-
-pub const BREATHER_DURATION: f32 = 7.0;
-
 pub fn register(app: &mut App) {
     app.init_resource::<RunState>()
         .add_message::<StartWaveEvent>()
@@ -57,9 +52,12 @@ fn tick_run(time: Res<Time>, mut run_state: ResMut<RunState>) {
 fn check_combat_timeout(
     mut commands: Commands,
     run_state: Res<RunState>,
+    waves: Res<WavesConfig>,
+    globals: Res<Globals>,
     enemies: Query<(Entity, &Transform, Has<ScaleModifiers>), With<WaveEnemy>>,
 ) {
-    if run_state.elapsed < wave_duration(run_state.wave) {
+    let wave = waves.for_wave(run_state.wave, &globals.endless);
+    if run_state.elapsed < wave.duration {
         return;
     }
     for (e, t, has) in &enemies {
@@ -78,7 +76,7 @@ fn check_combat_timeout(
         );
     }
     commands.insert_resource(BreatherTimer(Timer::from_seconds(
-        BREATHER_DURATION,
+        wave.breather,
         TimerMode::Once,
     )));
 }
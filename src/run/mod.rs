@@ -8,7 +8,7 @@ mod run_scope;
 
 pub use combat_scope::{CombatScoped, SkipDeathShrink};
 pub use death::PlayerDying;
-pub use lifecycle::{wave_duration, BreatherTimer, RunState, StartWaveEvent};
+pub use lifecycle::{BreatherTimer, RunState, StartWaveEvent};
 pub use run_scope::RunScoped;
 
 pub struct RunPlugin;
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::actors::components::{Health, Shadow, Shape, ShapeColor, ShapeKind, Size};
+use crate::actors::components::combat::PendingHeal;
+use crate::actors::components::{Shadow, Shape, ShapeColor, ShapeKind, Size};
 use crate::actors::{death_system, DeathEvent, Player};
 use crate::game_state::GameState;
 use crate::palette;
@@ -154,13 +155,16 @@ fn move_pills(
 fn collect_pills(
     mut commands: Commands,
     pills: Query<(Entity, &PillAttracted), With<HealthPill>>,
-    mut player_query: Query<(&mut Health, &ComputedStats), With<Player>>,
+    player_query: Query<(Entity, &ComputedStats), With<Player>>,
+    mut heals: MessageWriter<PendingHeal>,
 ) {
-    let Ok((mut health, computed)) = player_query.single_mut() else { return };
+    let Ok((player, computed)) = player_query.single() else { return };
     for (entity, a) in &pills {
         if a.elapsed >= PILL_ATTRACTION_DURATION {
-            let max = computed.final_of(Stat::MaxLife).max(1.0);
-            health.current = (health.current + max * PILL_HEAL_PCT).min(max);
+            heals.write(PendingHeal {
+                target: player,
+                amount: computed.final_of(Stat::MaxLife).max(1.0) * PILL_HEAL_PCT,
+            });
             commands.entity(entity).despawn();
         }
     }
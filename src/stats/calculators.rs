@@ -1,46 +1,72 @@
 use bevy::prelude::*;
 
-use super::registry::{ModifierKind, Stat};
-use super::{ComputedStats, DirtyStats, Modifiers};
+use super::registry::{Formula, ModifierKind, Stat};
+use super::{ComputedStats, DirtyStats, Modifiers, StatSet};
+
+type StatTable<T> = [Vec<T>; Stat::COUNT];
 
 #[derive(Resource)]
 pub struct StatCalculators {
+    formulas: [Formula; Stat::COUNT],
+    overflow_into: StatTable<(Stat, f32, f32)>,
     calculation_order: Vec<Stat>,
-    reverse_deps: [Vec<Stat>; Stat::COUNT],
+    reverse_deps: StatTable<Stat>,
 }
 
 impl StatCalculators {
     pub fn build() -> Self {
-        let reverse_deps: [Vec<Stat>; Stat::COUNT] = std::array::from_fn(|_| Vec::new());
-        let mut this = Self {
-            calculation_order: Vec::new(),
-            reverse_deps,
-        };
-        this.rebuild();
-        this
+        Self::with_formulas(&[])
     }
 
-    fn rebuild(&mut self) {
-        self.calculation_order = self.topological_sort();
+    pub fn with_formulas(overrides: &[(Stat, Formula)]) -> Self {
+        Self::try_with_formulas(overrides).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        for deps in &mut self.reverse_deps {
-            deps.clear();
+    pub fn try_with_formulas(overrides: &[(Stat, Formula)]) -> Result<Self, String> {
+        let mut formulas = [Formula::FlatIncMore; Stat::COUNT];
+        for stat in Stat::iter() {
+            formulas[stat.index()] = stat.formula();
+        }
+        let mut overridden = StatSet::default();
+        let mut overflow_into: StatTable<(Stat, f32, f32)> = std::array::from_fn(|_| Vec::new());
+        let mut deps: StatTable<Stat> = std::array::from_fn(|_| Vec::new());
+        for &(stat, formula) in overrides {
+            formulas[stat.index()] = formula;
+            overridden.insert(stat);
+            if let Formula::Capped { cap, overflow: Some((into, ratio)) } = formula {
+                overflow_into[into.index()].push((stat, cap, ratio));
+                deps[into.index()].push(stat);
+            }
+        }
+        for stat in Stat::iter().filter(|s| !overridden.contains(*s)) {
+            deps[stat.index()].extend_from_slice(stat.deps());
         }
 
+        let mut reverse_deps: StatTable<Stat> = std::array::from_fn(|_| Vec::new());
         for stat in Stat::iter() {
-            for &dep in stat.deps() {
-                self.reverse_deps[dep.index()].push(stat);
+            for &dep in &deps[stat.index()] {
+                reverse_deps[dep.index()].push(stat);
             }
         }
+        Ok(Self {
+            formulas,
+            overflow_into,
+            calculation_order: Self::topological_sort(&deps)?,
+            reverse_deps,
+        })
     }
 
-    fn topological_sort(&self) -> Vec<Stat> {
+    pub fn formula(&self, stat: Stat) -> Formula {
+        self.formulas[stat.index()]
+    }
+
+    fn topological_sort(deps: &StatTable<Stat>) -> Result<Vec<Stat>, String> {
         let n = Stat::COUNT;
         let mut in_degree: Vec<usize> = vec![0; n];
         let mut adjacency: Vec<Vec<Stat>> = vec![Vec::new(); n];
 
         for stat in Stat::iter() {
-            for &dep in stat.deps() {
+            for &dep in &deps[stat.index()] {
                 adjacency[dep.index()].push(stat);
                 in_degree[stat.index()] += 1;
             }
@@ -69,19 +95,19 @@ impl StatCalculators {
                 .filter(|s| in_degree[s.index()] > 0)
                 .map(|s| s.name())
                 .collect();
-            panic!(
+            return Err(format!(
                 "Circular dependency detected in stats calculation! Stats involved: {:?}",
                 cycle_members
-            );
+            ));
         }
 
-        result
+        Ok(result)
     }
 
     pub fn invalidate(&self, stat: Stat, dirty: &mut DirtyStats) {
         dirty.mark(stat);
         for &dependent in &self.reverse_deps[stat.index()] {
-            if !dirty.stats.contains(&dependent) {
+            if !dirty.contains(dependent) {
                 self.invalidate(dependent, dirty);
             }
         }
@@ -98,15 +124,147 @@ impl StatCalculators {
         }
 
         for &stat in &self.calculation_order {
-            if dirty.stats.contains(&stat) {
+            if dirty.contains(stat) {
                 computed.set_bucket(stat, ModifierKind::Flat, modifiers.sum(stat, ModifierKind::Flat));
                 computed.set_bucket(stat, ModifierKind::Increased, modifiers.sum(stat, ModifierKind::Increased));
                 computed.set_bucket(stat, ModifierKind::More, modifiers.product(stat, ModifierKind::More));
-                let final_value = computed.apply(stat, 0.0);
-                computed.set_final(stat, final_value);
+                let formula = self.formulas[stat.index()];
+                let overflow: f32 = self.overflow_into[stat.index()]
+                    .iter()
+                    .map(|&(from, cap, ratio)| (computed.uncapped_of(from) - cap).max(0.0) * ratio)
+                    .sum();
+                computed.set_uncapped(stat, computed.raw(formula, stat, 0.0));
+                computed.set_final(stat, computed.apply_formula(formula, stat, 0.0) + overflow);
+                dirty.mark_all(self.reverse_deps[stat.index()].iter().copied());
             }
         }
 
         dirty.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modifiers(entries: &[(Stat, ModifierKind, f32)]) -> Modifiers {
+        let mut m = Modifiers::new();
+        for &(stat, kind, value) in entries {
+            m.add(stat, kind, value);
+        }
+        m
+    }
+
+    fn computed(calc: &StatCalculators, m: &Modifiers) -> ComputedStats {
+        let mut cs = ComputedStats::default();
+        let mut dirty = DirtyStats::default();
+        dirty.mark_all(Stat::iter());
+        calc.recalculate(m, &mut cs, &mut dirty);
+        cs
+    }
+
+    #[test]
+    fn derived_stats_read_dependency_finals() {
+        let calc = StatCalculators::build();
+        let m = modifiers(&[
+            (Stat::AreaOfEffect, ModifierKind::Flat, 0.5),
+            (Stat::Duration, ModifierKind::Flat, 1.0),
+            (Stat::SplashRadius, ModifierKind::Flat, 40.0),
+            (Stat::BurnDuration, ModifierKind::Flat, 2.0),
+        ]);
+        let cs = computed(&calc, &m);
+        assert_eq!(cs.final_of(Stat::SplashRadius), 60.0);
+        assert_eq!(cs.final_of(Stat::BurnDuration), 4.0);
+    }
+
+    #[test]
+    fn multiplier_leaves_out_the_flat_term() {
+        let calc = StatCalculators::build();
+        let m = modifiers(&[
+            (Stat::ProjectileSpeed, ModifierKind::Flat, 200.0),
+            (Stat::ProjectileSpeed, ModifierKind::Increased, 0.5),
+            (Stat::ProjectileSpeed, ModifierKind::More, 0.2),
+        ]);
+        let cs = computed(&calc, &m);
+        assert!((cs.multiplier_of(Stat::ProjectileSpeed) - 1.8).abs() < 1e-6);
+        assert!((cs.apply(Stat::ProjectileSpeed, 800.0) - 1000.0 * 1.8).abs() < 1e-3);
+    }
+
+    #[test]
+    fn max_life_caps_shield_block() {
+        let calc = StatCalculators::build();
+        let mut m = modifiers(&[
+            (Stat::MaxLife, ModifierKind::Flat, 100.0),
+            (Stat::ShieldMaxBlock, ModifierKind::Flat, 150.0),
+        ]);
+        let mut cs = computed(&calc, &m);
+        assert_eq!(cs.final_of(Stat::ShieldMaxBlock), 100.0);
+
+        m.add(Stat::MaxLife, ModifierKind::Flat, 100.0);
+        let mut dirty = DirtyStats::default();
+        calc.invalidate(Stat::MaxLife, &mut dirty);
+        assert!(dirty.contains(Stat::ShieldMaxBlock));
+        calc.recalculate(&m, &mut cs, &mut dirty);
+        assert_eq!(cs.final_of(Stat::ShieldMaxBlock), 150.0);
+
+        m.add(Stat::MaxLife, ModifierKind::More, -0.5);
+        dirty.mark(Stat::MaxLife);
+        calc.recalculate(&m, &mut cs, &mut dirty);
+        assert_eq!(cs.final_of(Stat::ShieldMaxBlock), 100.0);
+    }
+
+    #[test]
+    fn changing_a_dependency_recomputes_dependents() {
+        let calc = StatCalculators::build();
+        let mut m = modifiers(&[(Stat::SplashRadius, ModifierKind::Flat, 100.0)]);
+        let mut cs = computed(&calc, &m);
+        assert_eq!(cs.final_of(Stat::SplashRadius), 100.0);
+
+        m.add(Stat::AreaOfEffect, ModifierKind::Flat, 0.5);
+        let mut dirty = DirtyStats::default();
+        calc.invalidate(Stat::AreaOfEffect, &mut dirty);
+        assert!(dirty.contains(Stat::SplashRadius));
+        calc.recalculate(&m, &mut cs, &mut dirty);
+        assert_eq!(cs.final_of(Stat::SplashRadius), 150.0);
+
+        m.add(Stat::AreaOfEffect, ModifierKind::Flat, -0.25);
+        dirty.mark(Stat::AreaOfEffect);
+        calc.recalculate(&m, &mut cs, &mut dirty);
+        assert_eq!(cs.final_of(Stat::SplashRadius), 125.0);
+    }
+
+    #[test]
+    fn soft_caps_keep_the_uncapped_value() {
+        let calc = StatCalculators::with_formulas(&[
+            (Stat::DodgeChance, Formula::Hyperbolic { max: 0.75 }),
+            (Stat::AttackSpeed, Formula::Clamp { min: 0.25, max: 3.0 }),
+            (
+                Stat::CritChance,
+                Formula::Capped { cap: 1.0, overflow: Some((Stat::CritMultiplier, 2.0)) },
+            ),
+        ]);
+        let m = modifiers(&[
+            (Stat::DodgeChance, ModifierKind::Flat, 0.75),
+            (Stat::AttackSpeed, ModifierKind::Flat, 5.0),
+            (Stat::CritChance, ModifierKind::Flat, 1.25),
+            (Stat::CritMultiplier, ModifierKind::Flat, 1.5),
+        ]);
+        let cs = computed(&calc, &m);
+        assert_eq!(cs.final_of(Stat::DodgeChance), 0.375);
+        assert_eq!(cs.uncapped_of(Stat::DodgeChance), 0.75);
+        assert_eq!(cs.final_of(Stat::AttackSpeed), 3.0);
+        assert_eq!(cs.uncapped_of(Stat::AttackSpeed), 5.0);
+        assert_eq!(cs.final_of(Stat::CritChance), 1.0);
+        assert_eq!(cs.final_of(Stat::CritMultiplier), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Circular dependency")]
+    fn dependency_cycle_panics() {
+        let capped = |into| Formula::Capped { cap: 1.0, overflow: Some((into, 1.0)) };
+        StatCalculators::with_formulas(&[
+            (Stat::CritChance, capped(Stat::CritMultiplier)),
+            (Stat::CritMultiplier, capped(Stat::CritChance)),
+        ]);
+    }
+}
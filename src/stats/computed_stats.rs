@@ -6,6 +6,7 @@ use super::registry::{Formula, ModifierKind, Stat};
 pub struct ComputedStats {
     buckets: [[f32; ModifierKind::COUNT]; Stat::COUNT],
     finals: [f32; Stat::COUNT],
+    uncapped: [f32; Stat::COUNT],
 }
 
 impl Default for ComputedStats {
@@ -17,6 +18,7 @@ impl Default for ComputedStats {
         Self {
             buckets,
             finals: [0.0; Stat::COUNT],
+            uncapped: [0.0; Stat::COUNT],
         }
     }
 }
@@ -38,15 +40,52 @@ impl ComputedStats {
         self.finals[stat.index()]
     }
 
+    pub fn set_uncapped(&mut self, stat: Stat, value: f32) {
+        self.uncapped[stat.index()] = value;
+    }
+
+    pub fn uncapped_of(&self, stat: Stat) -> f32 {
+        self.uncapped[stat.index()]
+    }
+
+    pub fn raw(&self, formula: Formula, stat: Stat, base: f32) -> f32 {
+        match formula {
+            Formula::ScaledBy(by) => self.flat_inc_more(stat, base) * self.scale_of(by),
+            _ => self.flat_inc_more(stat, base),
+        }
+    }
+
     pub fn apply(&self, stat: Stat, base: f32) -> f32 {
-        match stat.formula() {
-            Formula::FlatIncMore => {
-                let flat = self.bucket(stat, ModifierKind::Flat);
-                let inc = self.bucket(stat, ModifierKind::Increased);
-                let more = self.bucket(stat, ModifierKind::More);
-                (base + flat) * (1.0 + inc) * more
-            }
-            Formula::Custom(f) => f(self, stat, base),
+        self.apply_formula(stat.formula(), stat, base)
+    }
+
+    pub fn apply_formula(&self, formula: Formula, stat: Stat, base: f32) -> f32 {
+        let raw = self.raw(formula, stat, base);
+        match formula {
+            Formula::FlatIncMore | Formula::ScaledBy(_) => raw,
+            Formula::Custom(_, f) => f(self, stat, base),
+            Formula::Clamp { min, max } => raw.clamp(min, max),
+            Formula::Hyperbolic { max } if raw > 0.0 => raw / (1.0 + raw / max),
+            Formula::Hyperbolic { .. } => raw,
+            Formula::Capped { cap, .. } => raw.min(cap),
         }
     }
+
+    pub fn scale_of(&self, stat: Stat) -> f32 {
+        (1.0 + self.final_of(stat)).max(0.0)
+    }
+
+    /// `(1 + increased) * more` without the flat term, for bases in other units than the stat.
+    pub fn multiplier_of(&self, stat: Stat) -> f32 {
+        let inc = self.bucket(stat, ModifierKind::Increased);
+        let more = self.bucket(stat, ModifierKind::More);
+        ((1.0 + inc) * more).max(0.0)
+    }
+
+    fn flat_inc_more(&self, stat: Stat, base: f32) -> f32 {
+        let flat = self.bucket(stat, ModifierKind::Flat);
+        let inc = self.bucket(stat, ModifierKind::Increased);
+        let more = self.bucket(stat, ModifierKind::More);
+        (base + flat) * (1.0 + inc) * more
+    }
 }
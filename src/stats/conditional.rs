@@ -0,0 +1,209 @@
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+
+use super::modifiers::ModifierSource;
+use super::registry::{ModifierKind, Stat};
+use super::{ComputedStats, Modifiers};
+use crate::actors::components::Health;
+use crate::Faction;
+
+const MOVING_SPEED: f32 = 10.0;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Condition {
+    LifeAbove(f32),
+    LifeBelow(f32),
+    Moving,
+    Stationary,
+    RecentlyHit(f32),
+    EnemiesWithin { radius: f32, count: u32 },
+}
+
+#[derive(Component)]
+pub struct TimeSinceHit(pub f32);
+
+impl Default for TimeSinceHit {
+    fn default() -> Self {
+        Self(f32::INFINITY)
+    }
+}
+
+struct ConditionalEntry {
+    owner: ModifierSource,
+    source: ModifierSource,
+    condition: Condition,
+    entries: Vec<(Stat, ModifierKind, f32)>,
+    active: bool,
+}
+
+#[derive(Component, Default)]
+#[require(TimeSinceHit)]
+pub struct ConditionalModifiers {
+    list: Vec<ConditionalEntry>,
+    next_id: u32,
+}
+
+impl ConditionalModifiers {
+    pub fn add(
+        &mut self,
+        owner: ModifierSource,
+        condition: Condition,
+        entries: Vec<(Stat, ModifierKind, f32)>,
+    ) {
+        let source = ModifierSource::Condition(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        self.list.push(ConditionalEntry {
+            owner,
+            source,
+            condition,
+            entries,
+            active: false,
+        });
+    }
+
+    /// Flips every entry whose condition changed, adding or removing its modifier source.
+    /// Returns whether any condition flipped, i.e. whether `modifiers` was touched.
+    fn update(
+        &mut self,
+        modifiers: &mut Modifiers,
+        mut holds: impl FnMut(Condition) -> bool,
+    ) -> bool {
+        let mut flipped = false;
+        for entry in &mut self.list {
+            if holds(entry.condition) == entry.active {
+                continue;
+            }
+            flipped = true;
+            entry.active = !entry.active;
+            if entry.active {
+                modifiers.replace_source(entry.source, entry.entries.iter().copied());
+            } else {
+                modifiers.remove_source(entry.source);
+            }
+        }
+        flipped
+    }
+
+    pub fn remove_owner(&mut self, owner: ModifierSource, modifiers: &mut Modifiers) {
+        self.list.retain(|e| {
+            if e.owner != owner {
+                return true;
+            }
+            if e.active {
+                modifiers.remove_source(e.source);
+            }
+            false
+        });
+    }
+}
+
+type ConditionOwner = (
+    Entity,
+    &'static mut ConditionalModifiers,
+    &'static mut Modifiers,
+    &'static mut TimeSinceHit,
+    &'static ComputedStats,
+    &'static Transform,
+    &'static Faction,
+    Option<&'static Health>,
+    Option<&'static LinearVelocity>,
+);
+
+pub fn evaluate_conditions(
+    time: Res<Time>,
+    mut owners: Query<ConditionOwner>,
+    others: Query<(Entity, &Transform, &Faction), With<Health>>,
+) {
+    let dt = time.delta_secs();
+    let mut positions: Option<Vec<(Entity, Vec2, Faction)>> = None;
+    for (entity, mut conditional, mut modifiers, mut since_hit, stats, transform, faction, health, velocity) in
+        &mut owners
+    {
+        since_hit.0 += dt;
+        if conditional.list.is_empty() {
+            continue;
+        }
+        let max_life = stats.final_of(Stat::MaxLife);
+        let life = match health {
+            Some(h) if max_life > 0.0 => h.current / max_life,
+            _ => 1.0,
+        };
+        let speed = velocity.map_or(0.0, |v| v.0.length());
+        let pos = crate::coord::to_2d(transform.translation);
+        let since_hit = since_hit.0;
+
+        let holds = |condition| match condition {
+            Condition::LifeAbove(fraction) => life >= fraction,
+            Condition::LifeBelow(fraction) => life < fraction,
+            Condition::Moving => speed > MOVING_SPEED,
+            Condition::Stationary => speed <= MOVING_SPEED,
+            Condition::RecentlyHit(window) => since_hit <= window,
+            Condition::EnemiesWithin { radius, count } => {
+                let positions = positions.get_or_insert_with(|| {
+                    others
+                        .iter()
+                        .map(|(e, t, f)| (e, crate::coord::to_2d(t.translation), *f))
+                        .collect()
+                });
+                let within = positions
+                    .iter()
+                    .filter(|(e, p, f)| {
+                        *e != entity && f != faction && p.distance_squared(pos) <= radius * radius
+                    })
+                    .count() as u32;
+                within >= count
+            }
+        };
+        if conditional.update(modifiers.bypass_change_detection(), holds) {
+            modifiers.set_changed();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_source(modifiers: &Modifiers, source: ModifierSource) -> bool {
+        modifiers.iter().any(|m| m.source == source)
+    }
+
+    #[test]
+    fn entries_flip_on_and_off_with_their_condition() {
+        let mut conditional = ConditionalModifiers::default();
+        let mut modifiers = Modifiers::new();
+        let owner = ModifierSource::Buff("owner");
+        conditional.add(
+            owner,
+            Condition::LifeBelow(0.5),
+            vec![(Stat::PhysicalDamage, ModifierKind::Increased, 0.2)],
+        );
+        let source = conditional.list[0].source;
+
+        assert!(!conditional.update(&mut modifiers, |_| false));
+        assert!(!has_source(&modifiers, source));
+        assert!(conditional.update(&mut modifiers, |_| true));
+        assert!(has_source(&modifiers, source));
+        assert_eq!(modifiers.sum(Stat::PhysicalDamage, ModifierKind::Increased), 0.2);
+        assert!(!conditional.update(&mut modifiers, |_| true));
+        assert!(conditional.update(&mut modifiers, |_| false));
+        assert!(!has_source(&modifiers, source));
+    }
+
+    #[test]
+    fn remove_owner_drops_active_sources() {
+        let mut conditional = ConditionalModifiers::default();
+        let mut modifiers = Modifiers::new();
+        let (owner, other) = (ModifierSource::Buff("owner"), ModifierSource::Buff("other"));
+        let entries = vec![(Stat::MovementSpeed, ModifierKind::Flat, 10.0)];
+        for o in [owner, other] {
+            conditional.add(o, Condition::Moving, entries.clone());
+        }
+        conditional.update(&mut modifiers, |_| true);
+        assert_eq!(modifiers.sum(Stat::MovementSpeed, ModifierKind::Flat), 20.0);
+
+        conditional.remove_owner(owner, &mut modifiers);
+        assert_eq!(conditional.list.len(), 1);
+        assert_eq!(modifiers.sum(Stat::MovementSpeed, ModifierKind::Flat), 10.0);
+    }
+}
@@ -1,11 +1,50 @@
 use bevy::prelude::*;
-use std::collections::HashSet;
 
 use super::Stat;
 
+const _: () = assert!(Stat::COUNT <= u64::BITS as usize);
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct StatSet(u64);
+
+impl StatSet {
+    pub fn insert(&mut self, stat: Stat) {
+        self.0 |= 1 << stat.index();
+    }
+
+    pub fn contains(self, stat: Stat) -> bool {
+        self.0 & (1 << stat.index()) != 0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Stat> {
+        Stat::iter().filter(move |s| self.contains(*s))
+    }
+}
+
+impl Extend<Stat> for StatSet {
+    fn extend<I: IntoIterator<Item = Stat>>(&mut self, iter: I) {
+        for stat in iter {
+            self.insert(stat);
+        }
+    }
+}
+
+impl IntoIterator for StatSet {
+    type Item = Stat;
+    type IntoIter = Box<dyn Iterator<Item = Stat>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
 #[derive(Component, Default)]
 pub struct DirtyStats {
-    pub stats: HashSet<Stat>,
+    pub stats: StatSet,
 }
 
 impl DirtyStats {
@@ -17,11 +56,15 @@ impl DirtyStats {
         self.stats.extend(stats);
     }
 
+    pub fn contains(&self, stat: Stat) -> bool {
+        self.stats.contains(stat)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.stats.is_empty()
     }
 
     pub fn clear(&mut self) {
-        self.stats.clear();
+        self.stats = StatSet::default();
     }
 }
@@ -0,0 +1,119 @@
+use super::modifiers::ModifierSource;
+use super::registry::{Formula, ModifierKind, Stat};
+use super::{ComputedStats, Modifiers, StatCalculators};
+
+#[derive(Clone, Debug)]
+pub struct Contribution {
+    pub source: ModifierSource,
+    pub flat: f32,
+    pub increased: f32,
+    pub more: f32,
+}
+
+#[derive(Clone)]
+pub struct StatBreakdown {
+    pub stat: Stat,
+    pub formula: Formula,
+    pub contributions: Vec<Contribution>,
+    pub uncapped: f32,
+    pub final_value: f32,
+}
+
+impl StatBreakdown {
+    pub fn is_capped(&self) -> bool {
+        (self.uncapped - self.final_value).abs() > 1e-4
+    }
+}
+
+pub fn explain(
+    stat: Stat,
+    modifiers: &Modifiers,
+    computed: &ComputedStats,
+    calculators: &StatCalculators,
+) -> StatBreakdown {
+    let mut contributions: Vec<Contribution> = Vec::new();
+    for m in modifiers.of(stat) {
+        let idx = match contributions.iter().position(|c| c.source == m.source) {
+            Some(idx) => idx,
+            None => {
+                contributions.push(Contribution {
+                    source: m.source,
+                    flat: 0.0,
+                    increased: 0.0,
+                    more: 1.0,
+                });
+                contributions.len() - 1
+            }
+        };
+        let c = &mut contributions[idx];
+        match m.kind {
+            ModifierKind::Flat => c.flat += m.value,
+            ModifierKind::Increased => c.increased += m.value,
+            ModifierKind::More => c.more *= 1.0 + m.value,
+        }
+    }
+    StatBreakdown {
+        stat,
+        formula: calculators.formula(stat),
+        contributions,
+        uncapped: computed.uncapped_of(stat),
+        final_value: computed.final_of(stat),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::ArtifactKind;
+    use crate::stats::DirtyStats;
+
+    const ARTIFACT: ModifierSource = ModifierSource::Artifact(ArtifactKind::ShadowVeil);
+
+    fn computed(calc: &StatCalculators, modifiers: &Modifiers) -> ComputedStats {
+        let mut cs = ComputedStats::default();
+        let mut dirty = DirtyStats::default();
+        dirty.mark_all(Stat::iter());
+        calc.recalculate(modifiers, &mut cs, &mut dirty);
+        cs
+    }
+
+    #[test]
+    fn groups_modifiers_per_source() {
+        let calc = StatCalculators::build();
+        let mut m = Modifiers::new();
+        m.add(Stat::PhysicalDamage, ModifierKind::Flat, 10.0);
+        m.add_from(ARTIFACT, Stat::PhysicalDamage, ModifierKind::Increased, 0.5);
+        m.add_from(ARTIFACT, Stat::PhysicalDamage, ModifierKind::More, 0.2);
+        m.add(Stat::PhysicalDamage, ModifierKind::Increased, 0.1);
+        m.add_from(ARTIFACT, Stat::PhysicalDamage, ModifierKind::More, 0.2);
+        let cs = computed(&calc, &m);
+
+        let b = explain(Stat::PhysicalDamage, &m, &cs, &calc);
+        let rows: Vec<_> = b
+            .contributions
+            .iter()
+            .map(|c| (c.source, c.flat, c.increased, (c.more * 100.0).round() / 100.0))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![(ModifierSource::Base, 10.0, 0.1, 1.0), (ARTIFACT, 0.0, 0.5, 1.44)]
+        );
+        assert!((b.final_value - 10.0 * 1.6 * 1.44).abs() < 1e-4);
+        assert!(!b.is_capped());
+    }
+
+    #[test]
+    fn capped_stat_keeps_the_uncapped_value() {
+        let clamp = Formula::Clamp { min: 0.25, max: 3.0 };
+        let calc = StatCalculators::with_formulas(&[(Stat::AttackSpeed, clamp)]);
+        let mut m = Modifiers::new();
+        m.add(Stat::AttackSpeed, ModifierKind::Flat, 2.0);
+        m.add_from(ARTIFACT, Stat::AttackSpeed, ModifierKind::Increased, 1.5);
+        let cs = computed(&calc, &m);
+
+        let b = explain(Stat::AttackSpeed, &m, &cs, &calc);
+        assert_eq!(b.uncapped, 5.0);
+        assert_eq!(b.final_value, 3.0);
+        assert!(b.is_capped());
+    }
+}
@@ -1,18 +1,28 @@
 mod calculators;
 mod computed_stats;
+mod conditional;
 mod dirty_stats;
+mod explain;
 mod modifiers;
 mod registry;
 mod systems;
+mod template;
+mod timed;
 
 pub use calculators::StatCalculators;
 pub use computed_stats::ComputedStats;
-pub use dirty_stats::DirtyStats;
-pub use modifiers::Modifiers;
-pub use registry::{ModifierKind, Stat};
+pub use conditional::{Condition, ConditionalModifiers, TimeSinceHit};
+pub use dirty_stats::{DirtyStats, StatSet};
+pub use explain::{explain, Contribution, StatBreakdown};
+pub use modifiers::{ModifierSource, Modifiers};
+pub use registry::{Formula, ModifierKind, Stat};
+pub use template::StatTemplate;
+pub use timed::{ApplyTimedModifier, Stacking, TimedModifier, TimedModifiers};
 
 use bevy::prelude::*;
 
+use crate::balance::StatsBalance;
+use crate::wave::CombatPhase;
 use crate::GameState;
 
 pub struct StatsPlugin;
@@ -20,9 +30,17 @@ pub struct StatsPlugin;
 impl Plugin for StatsPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(StatCalculators::build())
+            .add_message::<ApplyTimedModifier>()
             .add_systems(
                 PreUpdate,
-                (systems::mark_dirty_on_modifier_change, systems::recalculate_stats)
+                (
+                    systems::apply_stat_formulas.run_if(resource_changed::<StatsBalance>),
+                    timed::apply_timed_modifiers,
+                    (timed::tick_timed_modifiers, conditional::evaluate_conditions)
+                        .run_if(in_state(CombatPhase::Running)),
+                    systems::mark_dirty_on_modifier_change,
+                    systems::recalculate_stats,
+                )
                     .chain()
                     .run_if(not(in_state(GameState::Loading))),
             );
@@ -1,18 +1,53 @@
 use bevy::prelude::*;
-use std::collections::HashSet;
 
+use super::dirty_stats::StatSet;
 use super::registry::{ModifierKind, Stat};
+use super::{ConditionalModifiers, TimedModifiers};
+use crate::artifact::ArtifactKind;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ModifierSource {
+    Base,
+    Artifact(ArtifactKind),
+    Buff(&'static str),
+    Condition(u32),
+    WaveScaling,
+}
+
+impl std::fmt::Display for ModifierSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModifierSource::Base => write!(f, "base"),
+            ModifierSource::Artifact(kind) => write!(f, "artifact {}", kind.id()),
+            ModifierSource::Buff(id) => write!(f, "buff {id}"),
+            ModifierSource::Condition(n) => write!(f, "condition #{n}"),
+            ModifierSource::WaveScaling => write!(f, "wave scaling"),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Modifier {
+    pub source: ModifierSource,
     pub stat: Stat,
     pub kind: ModifierKind,
     pub value: f32,
 }
 
-#[derive(Component, Default)]
+#[derive(Component, Clone)]
+#[require(TimedModifiers, ConditionalModifiers)]
 pub struct Modifiers {
-    list: Vec<Modifier>,
+    buckets: [Vec<Modifier>; Stat::COUNT],
+    changed: StatSet,
+}
+
+impl Default for Modifiers {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| Vec::new()),
+            changed: StatSet::default(),
+        }
+    }
 }
 
 impl Modifiers {
@@ -21,40 +56,129 @@ impl Modifiers {
     }
 
     pub fn add(&mut self, stat: Stat, kind: ModifierKind, value: f32) {
-        self.list.push(Modifier { stat, kind, value });
+        self.add_from(ModifierSource::Base, stat, kind, value);
+    }
+
+    pub fn add_from(&mut self, source: ModifierSource, stat: Stat, kind: ModifierKind, value: f32) {
+        self.buckets[stat.index()].push(Modifier { source, stat, kind, value });
+        self.changed.insert(stat);
+    }
+
+    pub fn remove_source(&mut self, source: ModifierSource) {
+        for (stat, bucket) in Stat::iter().zip(&mut self.buckets) {
+            let before = bucket.len();
+            bucket.retain(|m| m.source != source);
+            if bucket.len() != before {
+                self.changed.insert(stat);
+            }
+        }
+    }
+
+    pub fn replace_source(
+        &mut self,
+        source: ModifierSource,
+        entries: impl IntoIterator<Item = (Stat, ModifierKind, f32)>,
+    ) {
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by_key(|(stat, _, _)| stat.index());
+        let unchanged = self
+            .iter()
+            .filter(|m| m.source == source)
+            .map(|m| (m.stat, m.kind, m.value))
+            .eq(entries.iter().copied());
+        if unchanged {
+            return;
+        }
+        self.remove_source(source);
+        for (stat, kind, value) in entries {
+            self.add_from(source, stat, kind, value);
+        }
+    }
+
+    pub fn take_changed(&mut self) -> StatSet {
+        std::mem::take(&mut self.changed)
     }
 
     pub fn sum(&self, stat: Stat, kind: ModifierKind) -> f32 {
-        self.list
+        self.buckets[stat.index()]
             .iter()
-            .filter(|m| m.stat == stat && m.kind == kind)
+            .filter(|m| m.kind == kind)
             .map(|m| m.value)
             .sum()
     }
 
     pub fn product(&self, stat: Stat, kind: ModifierKind) -> f32 {
-        let mut result = 1.0;
-        for m in &self.list {
-            if m.stat == stat && m.kind == kind {
-                result *= 1.0 + m.value;
-            }
-        }
-        result
+        self.buckets[stat.index()]
+            .iter()
+            .filter(|m| m.kind == kind)
+            .map(|m| 1.0 + m.value)
+            .product()
+    }
+
+    pub fn of(&self, stat: Stat) -> impl Iterator<Item = &Modifier> {
+        self.buckets[stat.index()].iter()
     }
 
-    #[allow(dead_code)]
     pub fn iter(&self) -> impl Iterator<Item = &Modifier> {
-        self.list.iter()
+        self.buckets.iter().flatten()
     }
+}
 
-    pub fn affected_stats(&self) -> impl Iterator<Item = Stat> + '_ {
-        let mut seen = HashSet::new();
-        self.list.iter().filter_map(move |m| {
-            if seen.insert(m.stat) {
-                Some(m.stat)
-            } else {
-                None
-            }
-        })
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUFF: ModifierSource = ModifierSource::Buff("test_buff");
+
+    fn buffed() -> Modifiers {
+        let mut modifiers = Modifiers::new();
+        modifiers.add(Stat::PhysicalDamage, ModifierKind::Flat, 10.0);
+        modifiers.add_from(BUFF, Stat::PhysicalDamage, ModifierKind::Increased, 0.2);
+        modifiers.add_from(BUFF, Stat::MaxLife, ModifierKind::Flat, 5.0);
+        modifiers.take_changed();
+        modifiers
+    }
+
+    #[test]
+    fn replace_source_with_same_entries_changes_nothing() {
+        let mut modifiers = buffed();
+        modifiers.replace_source(
+            BUFF,
+            [
+                (Stat::MaxLife, ModifierKind::Flat, 5.0),
+                (Stat::PhysicalDamage, ModifierKind::Increased, 0.2),
+            ],
+        );
+        assert!(modifiers.take_changed().is_empty());
+        assert_eq!(modifiers.iter().filter(|m| m.source == BUFF).count(), 2);
+    }
+
+    #[test]
+    fn replace_source_swaps_only_that_source() {
+        let mut modifiers = buffed();
+        modifiers.replace_source(BUFF, [(Stat::PhysicalDamage, ModifierKind::Increased, 0.5)]);
+        let changed = modifiers.take_changed();
+        assert!(changed.contains(Stat::PhysicalDamage));
+        assert!(changed.contains(Stat::MaxLife));
+        assert_eq!(modifiers.sum(Stat::PhysicalDamage, ModifierKind::Increased), 0.5);
+        assert_eq!(modifiers.sum(Stat::PhysicalDamage, ModifierKind::Flat), 10.0);
+        assert_eq!(modifiers.sum(Stat::MaxLife, ModifierKind::Flat), 0.0);
+    }
+
+    #[test]
+    fn remove_source_marks_only_touched_stats() {
+        let mut modifiers = buffed();
+        modifiers.add(Stat::MovementSpeed, ModifierKind::Flat, 1.0);
+        modifiers.take_changed();
+        modifiers.remove_source(BUFF);
+        let changed = modifiers.take_changed();
+        assert!(changed.contains(Stat::PhysicalDamage));
+        assert!(changed.contains(Stat::MaxLife));
+        assert!(!changed.contains(Stat::MovementSpeed));
+        assert!(modifiers.iter().all(|m| m.source == ModifierSource::Base));
+        assert_eq!(modifiers.iter().count(), 2);
+
+        modifiers.remove_source(BUFF);
+        assert!(modifiers.take_changed().is_empty());
     }
 }
@@ -10,7 +10,9 @@ use super::ComputedStats;
 #[strum(serialize_all = "snake_case")]
 pub enum Stat {
     MaxLife,
+    LifeRegen,
     MaxMana,
+    ManaRegen,
     PhysicalDamage,
     MovementSpeed,
     ProjectileSpeed,
@@ -22,7 +24,12 @@ pub enum Stat {
     Duration,
     PickupRadius,
     DodgeChance,
+    Armor,
+    DamageReduction,
+    DamageTakenIncreased,
     Lifesteal,
+    HealingReceived,
+    WaveStartHeal,
     Thorns,
     KnockbackForce,
     Pierce,
@@ -56,14 +63,22 @@ impl Stat {
     pub fn formula(self) -> Formula {
         match self {
             Stat::CritChance | Stat::DodgeChance | Stat::FreezeChance => {
-                Formula::Custom(clamped_chance)
+                Formula::Custom("clamped_chance", clamped_chance)
             }
+            Stat::SplashRadius => Formula::ScaledBy(Stat::AreaOfEffect),
+            Stat::BurnDuration | Stat::FreezeDuration => Formula::ScaledBy(Stat::Duration),
+            Stat::ShieldMaxBlock => Formula::Custom("life_capped_shield", life_capped_shield),
             _ => Formula::FlatIncMore,
         }
     }
 
     pub fn deps(self) -> &'static [Stat] {
-        &[]
+        match self {
+            Stat::SplashRadius => &[Stat::AreaOfEffect],
+            Stat::BurnDuration | Stat::FreezeDuration => &[Stat::Duration],
+            Stat::ShieldMaxBlock => &[Stat::MaxLife],
+            _ => &[],
+        }
     }
 }
 
@@ -82,10 +97,32 @@ impl ModifierKind {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub enum Formula {
     FlatIncMore,
-    Custom(fn(&ComputedStats, Stat, f32) -> f32),
+    ScaledBy(Stat),
+    Custom(&'static str, fn(&ComputedStats, Stat, f32) -> f32),
+    Clamp { min: f32, max: f32 },
+    Hyperbolic { max: f32 },
+    Capped { cap: f32, overflow: Option<(Stat, f32)> },
+}
+
+impl Formula {
+    pub fn describe(self) -> String {
+        match self {
+            Formula::FlatIncMore => "flat * (1 + increased) * more".to_string(),
+            Formula::ScaledBy(by) => {
+                format!("flat * (1 + increased) * more * (1 + {})", by.name())
+            }
+            Formula::Custom(name, _) => name.to_string(),
+            Formula::Clamp { min, max } => format!("clamp(raw, {min}, {max})"),
+            Formula::Hyperbolic { max } => format!("raw / (1 + raw / {max})"),
+            Formula::Capped { cap, overflow: None } => format!("min(raw, {cap})"),
+            Formula::Capped { cap, overflow: Some((into, ratio)) } => {
+                format!("min(raw, {cap}), overflow x{ratio} into {}", into.name())
+            }
+        }
+    }
 }
 
 fn clamped_chance(cs: &ComputedStats, stat: Stat, base: f32) -> f32 {
@@ -93,3 +130,15 @@ fn clamped_chance(cs: &ComputedStats, stat: Stat, base: f32) -> f32 {
     let inc = cs.bucket(stat, ModifierKind::Increased);
     ((base + flat) * (1.0 + inc)).clamp(0.0, 1.0)
 }
+
+/// A shield never blocks more than the player's max life; at the shipped numbers
+/// (20 life, Guardian Aegis 15) the cap only bites once block is stacked past life.
+pub const SHIELD_LIFE_CAP: f32 = 1.0;
+
+fn life_capped_shield(cs: &ComputedStats, stat: Stat, base: f32) -> f32 {
+    let flat = cs.bucket(stat, ModifierKind::Flat);
+    let inc = cs.bucket(stat, ModifierKind::Increased);
+    let more = cs.bucket(stat, ModifierKind::More);
+    let cap = cs.final_of(Stat::MaxLife) * SHIELD_LIFE_CAP;
+    ((base + flat) * (1.0 + inc) * more).min(cap)
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use super::{ComputedStats, DirtyStats, Modifiers, StatCalculators};
+use super::{ComputedStats, DirtyStats, Modifiers, Stat, StatCalculators};
+use crate::balance::StatsBalance;
 
 pub fn recalculate_stats(
     calculators: Res<StatCalculators>,
@@ -16,11 +17,22 @@ pub fn recalculate_stats(
 
 pub fn mark_dirty_on_modifier_change(
     calculators: Res<StatCalculators>,
-    mut query: Query<(&Modifiers, &mut DirtyStats), Changed<Modifiers>>,
+    mut query: Query<(&mut Modifiers, &mut DirtyStats), Changed<Modifiers>>,
 ) {
-    for (modifiers, mut dirty) in &mut query {
-        for stat in modifiers.affected_stats() {
+    for (mut modifiers, mut dirty) in &mut query {
+        for stat in modifiers.bypass_change_detection().take_changed() {
             calculators.invalidate(stat, &mut dirty);
         }
     }
 }
+
+pub fn apply_stat_formulas(
+    balance: Res<StatsBalance>,
+    mut calculators: ResMut<StatCalculators>,
+    mut query: Query<&mut DirtyStats>,
+) {
+    *calculators = StatCalculators::with_formulas(&balance.formulas);
+    for mut dirty in &mut query {
+        dirty.mark_all(Stat::iter());
+    }
+}
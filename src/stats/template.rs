@@ -0,0 +1,42 @@
+use super::modifiers::ModifierSource;
+use super::registry::{ModifierKind, Stat};
+use super::{ComputedStats, DirtyStats, Modifiers, StatCalculators};
+
+#[derive(Clone)]
+pub struct StatTemplate {
+    modifiers: Modifiers,
+    computed: ComputedStats,
+}
+
+impl StatTemplate {
+    pub fn build(
+        calculators: &StatCalculators,
+        entries: impl IntoIterator<Item = (Stat, ModifierKind, f32)>,
+    ) -> Self {
+        let mut modifiers = Modifiers::new();
+        modifiers.replace_source(ModifierSource::Base, entries);
+        modifiers.take_changed();
+        let mut computed = ComputedStats::default();
+        let mut dirty = DirtyStats::default();
+        dirty.mark_all(Stat::iter());
+        calculators.recalculate(&modifiers, &mut computed, &mut dirty);
+        Self { modifiers, computed }
+    }
+
+    /// Clones the base modifiers on purpose: every instance adds its own source right away,
+    /// so sharing them behind an `Arc` would copy on the first write anyway.
+    pub fn instantiate(
+        &self,
+        calculators: &StatCalculators,
+        source: ModifierSource,
+        entries: impl IntoIterator<Item = (Stat, ModifierKind, f32)>,
+    ) -> (Modifiers, DirtyStats, ComputedStats) {
+        let mut modifiers = self.modifiers.clone();
+        let mut computed = self.computed.clone();
+        let mut dirty = DirtyStats::default();
+        modifiers.replace_source(source, entries);
+        dirty.mark_all(modifiers.take_changed());
+        calculators.recalculate(&modifiers, &mut computed, &mut dirty);
+        (modifiers, dirty, computed)
+    }
+}
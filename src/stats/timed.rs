@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+
+use super::modifiers::ModifierSource;
+use super::registry::{ModifierKind, Stat};
+use super::Modifiers;
+
+/// `Refresh` keeps one stack and restarts its timer on reapply. `Stack` shares a single timer
+/// across all stacks: each new stack restarts it, so the whole pile expires together.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Stacking {
+    Refresh,
+    Stack { max: u32 },
+}
+
+#[derive(Clone, Debug)]
+pub struct TimedModifier {
+    pub id: &'static str,
+    pub entries: Vec<(Stat, ModifierKind, f32)>,
+    pub duration: f32,
+    pub stacking: Stacking,
+}
+
+#[derive(Message)]
+pub struct ApplyTimedModifier {
+    pub target: Entity,
+    pub modifier: TimedModifier,
+}
+
+struct ActiveTimed {
+    modifier: TimedModifier,
+    stacks: u32,
+    remaining: f32,
+    synced: bool,
+}
+
+impl ActiveTimed {
+    fn entries(&self) -> impl Iterator<Item = (Stat, ModifierKind, f32)> + '_ {
+        let stacks = self.stacks as f32;
+        self.modifier
+            .entries
+            .iter()
+            .map(move |&(stat, kind, value)| (stat, kind, value * stacks))
+    }
+}
+
+#[derive(Component, Default)]
+pub struct TimedModifiers {
+    active: Vec<ActiveTimed>,
+}
+
+impl TimedModifiers {
+    pub fn apply(&mut self, modifier: TimedModifier) {
+        if let Some(active) = self.active.iter_mut().find(|a| a.modifier.id == modifier.id) {
+            active.stacks = match modifier.stacking {
+                Stacking::Refresh => 1,
+                Stacking::Stack { max } => (active.stacks + 1).min(max.max(1)),
+            };
+            active.remaining = modifier.duration;
+            active.modifier = modifier;
+            active.synced = false;
+            return;
+        }
+        self.active.push(ActiveTimed {
+            remaining: modifier.duration,
+            modifier,
+            stacks: 1,
+            synced: false,
+        });
+    }
+
+    /// Returns whether `modifiers` was touched: only on expiry or after an `apply`.
+    fn tick(&mut self, dt: f32, modifiers: &mut Modifiers) -> bool {
+        let mut touched = false;
+        self.active.retain_mut(|active| {
+            active.remaining -= dt;
+            let source = ModifierSource::Buff(active.modifier.id);
+            if active.remaining <= 0.0 {
+                modifiers.remove_source(source);
+                touched = true;
+                return false;
+            }
+            if !active.synced {
+                modifiers.replace_source(source, active.entries());
+                active.synced = true;
+                touched = true;
+            }
+            true
+        });
+        touched
+    }
+
+    fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+}
+
+pub fn apply_timed_modifiers(
+    mut events: MessageReader<ApplyTimedModifier>,
+    mut query: Query<&mut TimedModifiers>,
+) {
+    for ev in events.read() {
+        if let Ok(mut timed) = query.get_mut(ev.target) {
+            timed.apply(ev.modifier.clone());
+        }
+    }
+}
+
+pub fn tick_timed_modifiers(
+    time: Res<Time>,
+    mut query: Query<(&mut TimedModifiers, &mut Modifiers)>,
+) {
+    let dt = time.delta_secs();
+    for (mut timed, mut modifiers) in &mut query {
+        if timed.is_empty() {
+            continue;
+        }
+        if timed.tick(dt, modifiers.bypass_change_detection()) {
+            modifiers.set_changed();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buff(duration: f32, stacking: Stacking) -> TimedModifier {
+        TimedModifier {
+            id: "test_buff",
+            entries: vec![(Stat::PhysicalDamage, ModifierKind::Increased, 0.1)],
+            duration,
+            stacking,
+        }
+    }
+
+    fn damage(modifiers: &Modifiers) -> f32 {
+        modifiers.sum(Stat::PhysicalDamage, ModifierKind::Increased)
+    }
+
+    #[test]
+    fn tick_touches_modifiers_only_when_the_buff_changes() {
+        let mut timed = TimedModifiers::default();
+        let mut modifiers = Modifiers::new();
+        timed.apply(buff(1.0, Stacking::Stack { max: 3 }));
+        assert!(timed.tick(0.1, &mut modifiers));
+        assert!(!timed.tick(0.1, &mut modifiers));
+        timed.apply(buff(1.0, Stacking::Stack { max: 3 }));
+        assert!(timed.tick(0.1, &mut modifiers));
+        assert!(!timed.tick(0.5, &mut modifiers));
+        assert!(timed.tick(0.5, &mut modifiers));
+        assert!(timed.is_empty());
+    }
+
+    #[test]
+    fn stacks_cap_at_max() {
+        let mut timed = TimedModifiers::default();
+        let mut modifiers = Modifiers::new();
+        for _ in 0..5 {
+            timed.apply(buff(3.0, Stacking::Stack { max: 3 }));
+        }
+        timed.tick(0.1, &mut modifiers);
+        assert!((damage(&modifiers) - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn refresh_restarts_the_timer_with_one_stack() {
+        let mut timed = TimedModifiers::default();
+        let mut modifiers = Modifiers::new();
+        timed.apply(buff(10.0, Stacking::Refresh));
+        timed.tick(1.0, &mut modifiers);
+        timed.apply(buff(2.0, Stacking::Refresh));
+        timed.tick(1.5, &mut modifiers);
+        assert!((damage(&modifiers) - 0.1).abs() < 1e-6);
+        timed.tick(1.0, &mut modifiers);
+        assert!(timed.is_empty());
+    }
+
+    #[test]
+    fn expiry_removes_the_buff_source() {
+        let mut timed = TimedModifiers::default();
+        let mut modifiers = Modifiers::new();
+        modifiers.add(Stat::PhysicalDamage, ModifierKind::Increased, 0.5);
+        timed.apply(buff(1.0, Stacking::Stack { max: 2 }));
+        timed.apply(buff(1.0, Stacking::Stack { max: 2 }));
+        timed.tick(0.5, &mut modifiers);
+        assert!((damage(&modifiers) - 0.7).abs() < 1e-6);
+        timed.tick(0.6, &mut modifiers);
+        assert!(timed.is_empty());
+        assert_eq!(damage(&modifiers), 0.5);
+        assert!(modifiers.iter().all(|m| m.source == ModifierSource::Base));
+    }
+}
@@ -3,16 +3,18 @@ use bevy::time::Real;
 use bevy::ui::UiGlobalTransform;
 
 use crate::actors::Health;
-use crate::actors::Player;
+use crate::actors::{Mob, Player};
 use crate::arena::{CameraAngle, CameraZoom};
 use crate::artifact::{ArtifactInventory, ArtifactKind, RebuildPlayerStateEvent};
+use crate::balance::{ArtifactsBalance, Globals, WavesConfig};
 use crate::game_state::GameState;
 use crate::palette;
-use crate::run::{wave_duration, RunState};
-use crate::stats::{DirtyStats, ModifierKind, Modifiers, Stat};
+use crate::run::RunState;
+use crate::stats::{ComputedStats, DirtyStats, ModifierKind, Modifiers, Stat, StatCalculators};
 use crate::wave::EnemySpawnPool;
 use crate::wave::CombatPhase;
 
+use super::stat_sheet::spawn_stat_sheet;
 use super::widgets::{button_node, panel_node, ReleasedButtons};
 
 const ANGLE_MIN: f32 = 1.0;
@@ -62,6 +64,15 @@ pub(super) struct EnemyToggleText(pub usize);
 #[derive(Component)]
 pub(super) struct ForceDropButton(pub ArtifactKind);
 
+#[derive(Component)]
+pub(super) struct InspectCycleButton(pub isize);
+
+#[derive(Component)]
+pub(super) struct InspectSheet;
+
+#[derive(Resource, Default)]
+pub(super) struct InspectTarget(pub Option<Entity>);
+
 pub(super) fn dev_menu_active(combat: Option<Res<State<CombatPhase>>>) -> bool {
     combat
         .map(|s| *s.get() == CombatPhase::DevMenu)
@@ -159,8 +170,15 @@ pub(super) fn spawn_dev_menu(
     camera_angle: Res<CameraAngle>,
     camera_zoom: Res<CameraZoom>,
     spawn_pool: Res<EnemySpawnPool>,
+    artifacts: Res<ArtifactsBalance>,
 ) {
-    let panel = build_dev_menu_panel(&mut commands, &camera_angle, &camera_zoom, &spawn_pool);
+    let panel = build_dev_menu_panel(
+        &mut commands,
+        &camera_angle,
+        &camera_zoom,
+        &spawn_pool,
+        &artifacts,
+    );
     commands
         .spawn((
             Name::new("DevMenuRoot"),
@@ -184,6 +202,7 @@ fn build_dev_menu_panel(
     camera_angle: &CameraAngle,
     camera_zoom: &CameraZoom,
     spawn_pool: &EnemySpawnPool,
+    artifacts: &ArtifactsBalance,
 ) -> Entity {
     let angle_t = (camera_angle.degrees - ANGLE_MIN) / (ANGLE_MAX - ANGLE_MIN);
     let zoom_t = (camera_zoom.height - ZOOM_MIN) / (ZOOM_MAX - ZOOM_MIN);
@@ -404,7 +423,7 @@ fn build_dev_menu_panel(
     for &kind in ArtifactKind::ALL {
         let entity = commands
             .spawn(cheat_button(
-                kind.def().name,
+                &artifacts.get(kind).name,
                 palette::color("ui_artifact"),
                 ForceDropButton(kind),
             ))
@@ -419,6 +438,45 @@ fn build_dev_menu_panel(
         .add_children(&force_drop_buttons)
         .id();
 
+    let inspect_label = commands
+        .spawn((
+            Text::new("Inspect Stats"),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(palette::color("ui_text_subtle")),
+            Node {
+                margin: UiRect::new(Val::Px(0.0), Val::Px(0.0), Val::Px(20.0), Val::Px(6.0)),
+                ..default()
+            },
+        ))
+        .id();
+    let inspect_prev = commands
+        .spawn(cheat_button("Prev", palette::color("ui_text"), InspectCycleButton(-1)))
+        .id();
+    let inspect_next = commands
+        .spawn(cheat_button("Next", palette::color("ui_text"), InspectCycleButton(1)))
+        .id();
+    let inspect_row = commands
+        .spawn((Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(10.0),
+            ..default()
+        },))
+        .add_children(&[inspect_prev, inspect_next])
+        .id();
+    let inspect_sheet = commands
+        .spawn((
+            InspectSheet,
+            Node {
+                flex_direction: FlexDirection::Column,
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+        ))
+        .id();
+
     let children = vec![
         title,
         angle_row,
@@ -428,6 +486,9 @@ fn build_dev_menu_panel(
         health_btn,
         damage_btn,
         skip_btn,
+        inspect_label,
+        inspect_row,
+        inspect_sheet,
         force_drop_section,
         enemy_container,
     ];
@@ -477,20 +538,23 @@ pub(super) fn cheat_damage(
 pub(super) fn cheat_skip_wave(
     buttons: ReleasedButtons<CheatSkipWaveButton>,
     mut run_state: ResMut<RunState>,
+    waves: Res<WavesConfig>,
+    globals: Res<Globals>,
 ) {
     buttons.for_each(|_| {
-        run_state.elapsed = wave_duration(run_state.wave) + 0.1;
+        run_state.elapsed = waves.for_wave(run_state.wave, &globals.endless).duration + 0.1;
     });
 }
 
 pub(super) fn cheat_force_drop(
     buttons: ReleasedButtons<ForceDropButton>,
     mut inventory: ResMut<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
     mut rebuild: MessageWriter<RebuildPlayerStateEvent>,
 ) {
     buttons.for_each(|btn| {
         if !inventory.contains(btn.0) {
-            inventory.add(btn.0);
+            inventory.add(btn.0, &artifacts);
             rebuild.write(RebuildPlayerStateEvent);
         }
     });
@@ -698,3 +762,63 @@ pub(super) fn dev_trigger_double_tap(
         }
     }
 }
+
+pub(super) fn cycle_inspect_target(
+    buttons: ReleasedButtons<InspectCycleButton>,
+    mut target: ResMut<InspectTarget>,
+    candidates: Query<(Entity, Has<Player>), With<ComputedStats>>,
+) {
+    buttons.for_each(|step| {
+        let mut entities: Vec<(bool, Entity)> =
+            candidates.iter().map(|(e, is_player)| (!is_player, e)).collect();
+        entities.sort();
+        if entities.is_empty() {
+            return;
+        }
+        let current = target
+            .0
+            .and_then(|t| entities.iter().position(|(_, e)| *e == t))
+            .unwrap_or(0) as isize;
+        let next = (current + step.0).rem_euclid(entities.len() as isize) as usize;
+        target.0 = Some(entities[next].1);
+    });
+}
+
+pub(super) fn refresh_inspect_sheet(
+    mut commands: Commands,
+    target: Res<InspectTarget>,
+    sheet_q: Query<(Entity, Ref<InspectSheet>)>,
+    stats_q: Query<(&Modifiers, &ComputedStats, Option<&Mob>)>,
+    player_q: Query<Entity, With<Player>>,
+    calculators: Res<StatCalculators>,
+) {
+    let Ok((sheet, marker)) = sheet_q.single() else { return };
+    if !target.is_changed() && !marker.is_added() {
+        return;
+    }
+    commands.entity(sheet).despawn_related::<Children>();
+    let inspected = target
+        .0
+        .filter(|e| stats_q.contains(*e))
+        .or_else(|| player_q.single().ok());
+    let Some((entity, (modifiers, computed, mob))) =
+        inspected.and_then(|e| stats_q.get(e).ok().map(|s| (e, s)))
+    else {
+        return;
+    };
+    let name = match mob {
+        Some(mob) => format!("{} ({entity})", mob.kind.id()),
+        None if player_q.contains(entity) => "Player".to_string(),
+        None => entity.to_string(),
+    };
+    commands.spawn((
+        ChildOf(sheet),
+        Text::new(name),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(palette::color("ui_text_title")),
+    ));
+    spawn_stat_sheet(&mut commands, sheet, modifiers, computed, &calculators);
+}
@@ -1,8 +1,9 @@
 use bevy::prelude::*;
 
-use crate::actors::{Health, Player};
+use crate::actors::{Health, Mana, Player};
 use crate::palette;
-use crate::run::{wave_duration, BreatherTimer, RunState};
+use crate::balance::{Globals, WavesConfig};
+use crate::run::{BreatherTimer, RunState};
 use crate::stats::{ComputedStats, Stat};
 use crate::GameState;
 
@@ -23,6 +24,9 @@ pub struct LifeText;
 #[derive(Component)]
 pub struct LifeBar;
 
+#[derive(Component)]
+pub struct ManaBar;
+
 pub fn spawn_hud(mut commands: Commands, run_state: Res<RunState>) {
     commands.spawn((
         Name::new("HudRoot"),
@@ -73,6 +77,26 @@ pub fn spawn_hud(mut commands: Commands, run_state: Res<RunState>) {
                     BackgroundColor(palette::color("ui_lifebar"))
                 )]
             ),
+            (
+                Node {
+                    width: Val::Px(320.0),
+                    height: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(3.0)),
+                    border_radius: BorderRadius::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(palette::color("ui_lifebar_bg")),
+                BorderColor::all(palette::color("ui_panel_border")),
+                children![(
+                    ManaBar,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(palette::color("ui_manabar"))
+                )]
+            ),
         ],
     ));
 
@@ -93,14 +117,18 @@ pub fn spawn_hud(mut commands: Commands, run_state: Res<RunState>) {
     ));
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_hud(
     run_state: Res<RunState>,
+    waves: Res<WavesConfig>,
+    globals: Res<Globals>,
     breather: Option<Res<BreatherTimer>>,
-    player_query: Query<(&Health, &ComputedStats), With<Player>>,
+    player_query: Query<(&Health, &ComputedStats, Option<&Mana>), With<Player>>,
     mut wave_text: Query<&mut Text, (With<WaveText>, Without<LifeText>, Without<CountdownText>)>,
     mut life_text: Query<&mut Text, (With<LifeText>, Without<WaveText>, Without<CountdownText>)>,
     mut countdown_text: Query<&mut Text, (With<CountdownText>, Without<WaveText>, Without<LifeText>)>,
-    mut life_bar: Query<&mut Node, With<LifeBar>>,
+    mut life_bar: Query<&mut Node, (With<LifeBar>, Without<ManaBar>)>,
+    mut mana_bar: Query<&mut Node, (With<ManaBar>, Without<LifeBar>)>,
 ) {
     if let Ok(mut text) = wave_text.single_mut() {
         **text = format!("Wave {}", run_state.wave);
@@ -110,13 +138,13 @@ pub fn update_hud(
         let remaining = if let Some(b) = breather.as_ref() {
             b.0.remaining_secs()
         } else {
-            let total = wave_duration(run_state.wave);
+            let total = waves.for_wave(run_state.wave, &globals.endless).duration;
             (total - run_state.elapsed).max(0.0)
         };
         **text = format!("{}", remaining.ceil() as u32);
     }
 
-    if let Ok((health, stats)) = player_query.single() {
+    if let Ok((health, stats, mana)) = player_query.single() {
         let max_life = stats.final_of(Stat::MaxLife);
         if let Ok(mut text) = life_text.single_mut() {
             **text = format!("Life: {}/{}", health.current as i32, max_life as i32);
@@ -129,5 +157,14 @@ pub fn update_hud(
             };
             node.width = Val::Percent(progress);
         }
+        if let (Some(mana), Ok(mut node)) = (mana, mana_bar.single_mut()) {
+            let max_mana = stats.final_of(Stat::MaxMana);
+            let progress = if max_mana > 0.0 {
+                (mana.current / max_mana * 100.0).clamp(0.0, 100.0)
+            } else {
+                0.0
+            };
+            node.width = Val::Percent(progress);
+        }
     }
 }
@@ -5,6 +5,7 @@ mod hud;
 mod loading;
 mod main_menu;
 mod pause_menu;
+mod stat_sheet;
 pub mod widgets;
 
 use bevy::prelude::*;
@@ -54,6 +55,7 @@ impl Plugin for UiPlugin {
         #[cfg(feature = "dev")]
         {
             app.init_resource::<dev_menu::DevTriggerState>()
+                .init_resource::<dev_menu::InspectTarget>()
                 .add_systems(OnEnter(CombatPhase::DevMenu), dev_menu::spawn_dev_menu)
                 .add_systems(OnEnter(GameState::Playing), dev_menu::spawn_dev_trigger)
                 .add_systems(
@@ -76,6 +78,7 @@ impl Plugin for UiPlugin {
                         dev_menu::enable_all_enemies,
                         dev_menu::disable_all_enemies,
                         dev_menu::cheat_force_drop,
+                        (dev_menu::cycle_inspect_target, dev_menu::refresh_inspect_sheet).chain(),
                     )
                         .run_if(dev_menu::dev_menu_active),
                 );
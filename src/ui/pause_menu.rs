@@ -1,10 +1,14 @@
 use bevy::prelude::*;
 
+use crate::actors::Player;
 use crate::artifact::ArtifactInventory;
+use crate::balance::ArtifactsBalance;
 use crate::game_state::GameState;
 use crate::palette;
+use crate::stats::{ComputedStats, Modifiers, StatCalculators};
 use crate::wave::CombatPhase;
 
+use super::stat_sheet::spawn_stat_sheet;
 use super::widgets::{button_node, panel_node, ReleasedButtons};
 
 #[derive(Component)]
@@ -13,7 +17,13 @@ pub(super) enum PauseButton {
     EndRun,
 }
 
-pub(super) fn spawn_pause_menu(mut commands: Commands, inventory: Res<ArtifactInventory>) {
+pub(super) fn spawn_pause_menu(
+    mut commands: Commands,
+    inventory: Res<ArtifactInventory>,
+    artifacts: Res<ArtifactsBalance>,
+    player: Query<(&Modifiers, &ComputedStats), With<Player>>,
+    calculators: Res<StatCalculators>,
+) {
     let text = palette::color("ui_text");
     let root = commands
         .spawn((
@@ -140,9 +150,9 @@ pub(super) fn spawn_pause_menu(mut commands: Commands, inventory: Res<ArtifactIn
         ));
     } else {
         for kind in &inventory.collected {
-            let def = kind.def();
+            let def = artifacts.get(*kind);
             let disabled = inventory.disabled.contains(kind);
-            let border = palette::color("ui_artifact");
+            let border = palette::color(def.tier.palette_key());
             let label_color = if disabled {
                 palette::color("ui_text_disabled")
             } else {
@@ -176,7 +186,7 @@ pub(super) fn spawn_pause_menu(mut commands: Commands, inventory: Res<ArtifactIn
                         BackgroundColor(border),
                     ),
                     (
-                        Text::new(def.name),
+                        Text::new(def.name.clone()),
                         TextFont {
                             font_size: 18.0,
                             ..default()
@@ -187,6 +197,39 @@ pub(super) fn spawn_pause_menu(mut commands: Commands, inventory: Res<ArtifactIn
             ));
         }
     }
+
+    let Ok((modifiers, computed)) = player.single() else { return };
+    let stats_panel = commands
+        .spawn((
+            ChildOf(row),
+            panel_node(
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Stretch,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    width: Val::Px(360.0),
+                    max_height: Val::Px(560.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                None,
+            ),
+        ))
+        .id();
+    commands.spawn((
+        ChildOf(stats_panel),
+        Text::new("Stats"),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(palette::color("ui_text_title")),
+        Node {
+            margin: UiRect::bottom(Val::Px(8.0)),
+            ..default()
+        },
+    ));
+    spawn_stat_sheet(&mut commands, stats_panel, modifiers, computed, &calculators);
 }
 
 fn menu_button_node() -> Node {
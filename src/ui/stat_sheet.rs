@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+
+use crate::palette;
+use crate::stats::{explain, ComputedStats, Contribution, Modifiers, Stat, StatBreakdown, StatCalculators};
+
+pub(super) fn spawn_stat_sheet(
+    commands: &mut Commands,
+    parent: Entity,
+    modifiers: &Modifiers,
+    computed: &ComputedStats,
+    calculators: &StatCalculators,
+) {
+    for stat in Stat::iter() {
+        let breakdown = explain(stat, modifiers, computed, calculators);
+        if breakdown.contributions.is_empty() && breakdown.final_value == 0.0 {
+            continue;
+        }
+        commands.spawn((
+            ChildOf(parent),
+            Text::new(format!("{} = {}", stat.name(), value_text(&breakdown))),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(palette::color("ui_text")),
+            Node {
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+        ));
+        commands.spawn((
+            ChildOf(parent),
+            Text::new(breakdown.formula.describe()),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(palette::color("ui_text_subtle")),
+        ));
+        for c in &breakdown.contributions {
+            commands.spawn((
+                ChildOf(parent),
+                Text::new(format!("{}: {}", c.source, contribution_terms(c))),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(palette::color("ui_text_subtle")),
+                Node {
+                    margin: UiRect::left(Val::Px(12.0)),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+fn value_text(breakdown: &StatBreakdown) -> String {
+    if breakdown.is_capped() {
+        format!("{:.2} (uncapped {:.2})", breakdown.final_value, breakdown.uncapped)
+    } else {
+        format!("{:.2}", breakdown.final_value)
+    }
+}
+
+fn contribution_terms(c: &Contribution) -> String {
+    let mut parts = Vec::new();
+    if c.flat != 0.0 {
+        parts.push(format!("{:+.2} flat", c.flat));
+    }
+    if c.increased != 0.0 {
+        parts.push(format!("{:+.0}% increased", c.increased * 100.0));
+    }
+    if c.more != 1.0 {
+        parts.push(format!("x{:.2} more", c.more));
+    }
+    if parts.is_empty() {
+        return "no effect".to_string();
+    }
+    parts.join(", ")
+}
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
+use rand::seq::IndexedRandom;
 use rand::Rng;
 
 use crate::actors::Health;
@@ -17,12 +20,14 @@ use crate::GameState;
 #[derive(Resource)]
 pub struct EnemySpawnPool {
     pub enabled: Vec<(MobKind, bool)>,
+    pub weights: HashMap<MobKind, f32>,
 }
 
 impl Default for EnemySpawnPool {
     fn default() -> Self {
         Self {
             enabled: MobKind::iter().map(|k| (k, true)).collect(),
+            weights: HashMap::new(),
         }
     }
 }
@@ -31,6 +36,19 @@ impl EnemySpawnPool {
     pub fn active_kinds(&self) -> Vec<MobKind> {
         self.enabled.iter().filter(|(_, on)| *on).map(|(k, _)| *k).collect()
     }
+
+    pub fn weight(&self, kind: MobKind) -> f32 {
+        self.weights.get(&kind).copied().unwrap_or(1.0)
+    }
+
+    pub fn pick(&self, rng: &mut impl Rng) -> Option<MobKind> {
+        let active = self.active_kinds();
+        active
+            .choose_weighted(rng, |k| self.weight(*k))
+            .ok()
+            .or_else(|| active.choose(rng))
+            .copied()
+    }
 }
 
 pub fn register(app: &mut App) {
@@ -58,11 +76,12 @@ fn apply_wave_config(
     mut wave_state: ResMut<WaveState>,
     mut pool: ResMut<EnemySpawnPool>,
     waves: Res<WavesConfig>,
+    globals: Res<Globals>,
 ) {
     if events.read().last().is_none() {
         return;
     }
-    let def = waves.for_wave(run_state.wave);
+    let def = waves.for_wave(run_state.wave, &globals.endless);
     wave_state.spawn_interval = def.spawn_interval;
     wave_state.spawn_accumulator = def.spawn_interval;
     info!(
@@ -71,10 +90,11 @@ fn apply_wave_config(
     );
 
     let mut rng = rand::rng();
-    let active = waves.resolve_pool(run_state.wave, &mut rng);
+    let active = waves.resolve_pool(run_state.wave, &globals.endless, &mut rng);
     pool.enabled = MobKind::iter()
-        .map(|k| (k, active.contains(&k)))
+        .map(|k| (k, active.iter().any(|(a, _)| *a == k)))
         .collect();
+    pool.weights = active.into_iter().collect();
 }
 
 fn spawn_enemies(
@@ -130,11 +150,9 @@ fn spawn_enemies(
             }
         };
 
-        let active = spawn_pool.active_kinds();
-        if active.is_empty() {
+        let Some(kind) = spawn_pool.pick(&mut rng) else {
             break;
-        }
-        let kind = active[rng.random_range(0..active.len())];
+        };
         let circle_size = kind.size(&mobs_balance);
         let ground = crate::coord::ground_pos(Vec2::new(x, y));
 
@@ -163,10 +181,10 @@ fn spawn_enemies(
 
         if is_ghost {
             use crate::actors::GhostTransparency;
-            use crate::actors::mobs::ghost::{GHOST_INVISIBLE_DISTANCE, GHOST_VISIBLE_DISTANCE};
+            let ghost = &mobs_balance.ghost.behaviour;
             entity_commands.insert(GhostTransparency {
-                visible_distance: GHOST_VISIBLE_DISTANCE,
-                invisible_distance: GHOST_INVISIBLE_DISTANCE,
+                visible_distance: ghost.visible_distance,
+                invisible_distance: ghost.invisible_distance,
             });
         }
         wave_state.spawned_count += 1;
@@ -186,3 +204,38 @@ fn tag_wave_enemies(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn picks_follow_weights_and_skip_zero_weight_mobs() {
+        let pool = EnemySpawnPool {
+            enabled: MobKind::iter().map(|k| (k, k != MobKind::Jumper)).collect(),
+            weights: [
+                (MobKind::Ghost, 3.0),
+                (MobKind::SlimeSmall, 1.0),
+                (MobKind::Tower, 0.0),
+                (MobKind::Spinner, 0.0),
+                (MobKind::Jumper, 5.0),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+        let rolls = 10_000;
+        let mut counts: HashMap<MobKind, u32> = HashMap::new();
+        for _ in 0..rolls {
+            *counts.entry(pool.pick(&mut rng).unwrap()).or_default() += 1;
+        }
+        assert_eq!(counts.len(), 2, "{counts:?}");
+        for (kind, expected) in [(MobKind::Ghost, 0.75), (MobKind::SlimeSmall, 0.25)] {
+            let share = counts[&kind] as f32 / rolls as f32;
+            assert!((share - expected).abs() < 0.02, "{kind:?}: {share}");
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
-use crate::actors::{spawn_mob, Fade, MobKind, WaveModifiers};
+use crate::actors::{spawn_mob, Fade, MobKind, MobStatTemplates, WaveModifiers};
 use crate::actors::Health;
-use crate::balance::{MobsBalance, WavesConfig};
+use crate::balance::{Globals, MobsBalance, WavesConfig};
 use crate::dissolve_material::DissolveMaterial;
 use crate::particles::{self, ParticleEmitter, SpawnShape};
 use crate::run::{BreatherTimer, CombatScoped, PlayerDying, RunState};
@@ -122,12 +122,14 @@ fn animate_summoning(
     mut emitter_query: Query<&mut ParticleEmitter>,
     mobs_balance: Res<MobsBalance>,
     calculators: Res<StatCalculators>,
+    templates: Res<MobStatTemplates>,
     waves: Res<WavesConfig>,
+    globals: Res<Globals>,
     run_state: Res<RunState>,
     breather: Option<Res<BreatherTimer>>,
 ) {
     let dt = time.delta_secs();
-    let wave_def = waves.for_wave(run_state.wave);
+    let wave_def = waves.for_wave(run_state.wave, &globals.endless);
     let wave_mods = WaveModifiers {
         hp_mult: wave_def.hp_multiplier,
         damage_mult: wave_def.damage_multiplier,
@@ -173,6 +175,7 @@ fn animate_summoning(
                             pos,
                             &mobs_balance,
                             &calculators,
+                            &templates,
                             wave_mods,
                         );
                         commands.entity(mob).insert((